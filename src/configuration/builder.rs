@@ -107,6 +107,13 @@ impl ConfigurationBuilder {
     self.insert("quoteStyle", value.to_string().into())
   }
 
+  /// Whether to quote the keys of properties in object literals, type literals, interfaces, and enums.
+  ///
+  /// Default: `QuoteProps::Preserve`
+  pub fn quote_props(&mut self, value: QuoteProps) -> &mut Self {
+    self.insert("quoteProps", value.to_string().into())
+  }
+
   /// The JSX quote style to use for string literals in JSX attributes.
   ///
   /// Default: `JsxQuoteStyle::PreferDouble`
@@ -129,6 +136,25 @@ impl ConfigurationBuilder {
     self.insert("semiColons", value.to_string().into())
   }
 
+  /// Whether to align the line comments at the end of consecutive lines to the same column.
+  ///
+  /// Consecutive comments are not aligned when any of them would exceed the line width.
+  ///
+  /// Default: `false`
+  pub fn align_trailing_comments(&mut self, value: bool) -> &mut Self {
+    self.insert("alignTrailingComments", value.into())
+  }
+
+  /// A comment every file should start with (ex. a license header). It's inserted after
+  /// any shebang when missing and replaces the first comment when that's a variant of it.
+  ///
+  /// Text that isn't a comment is converted to line comments.
+  ///
+  /// Default: No header
+  pub fn file_header(&mut self, value: &str) -> &mut Self {
+    self.insert("fileHeader", value.to_string().into())
+  }
+
   /// Set to prefer hanging indentation when exceeding the line width.
   ///
   /// Default: `false`
@@ -429,6 +455,15 @@ impl ConfigurationBuilder {
     self.insert("arrowFunction.useParentheses", value.to_string().into())
   }
 
+  /// Whether to keep the headers of curried arrow functions (ex. `(a) => (b) => (c) => { ... }`)
+  /// on one line or place each arrow on a separate line when they exceed the line width.
+  ///
+  /// * `true` - Formats with each arrow on a new line when the headers span multiple lines.
+  /// * `false` (default) - Formats each arrow function on its own.
+  pub fn arrow_function_line_per_curried_arrow(&mut self, value: bool) -> &mut Self {
+    self.insert("arrowFunction.linePerCurriedArrow", value.into())
+  }
+
   /// Whether to force a line per expression when spanning multiple lines.
   ///
   /// * `true` - Formats with each part on a new line.
@@ -437,6 +472,96 @@ impl ConfigurationBuilder {
     self.insert("binaryExpression.linePerExpression", value.into())
   }
 
+  /// Whether to add parentheses around `&&` expressions mixed with `||` expressions.
+  ///
+  /// * `true` - Ex. `a && b || c` becomes `(a && b) || c`.
+  /// * `false` (default) - Maintains the parentheses as written by the programmer.
+  pub fn binary_expression_clarify_mixed_logical(&mut self, value: bool) -> &mut Self {
+    self.insert("binaryExpression.clarifyMixedLogical", value.into())
+  }
+
+  /// Whether to add parentheses around expressions mixed with bitwise or shift operators (ex. arithmetic in a shift).
+  ///
+  /// * `true` - Ex. `a + b << 2` becomes `(a + b) << 2` and `a & b | c` becomes `(a & b) | c`.
+  /// * `false` (default) - Maintains the parentheses as written by the programmer.
+  pub fn binary_expression_clarify_mixed_bitwise(&mut self, value: bool) -> &mut Self {
+    self.insert("binaryExpression.clarifyMixedBitwise", value.into())
+  }
+
+  /// Whether to add parentheses around a negated left operand of `instanceof` and `in` expressions.
+  ///
+  /// * `true` - Ex. `!a instanceof B` becomes `(!a) instanceof B`.
+  /// * `false` (default) - Maintains the parentheses as written by the programmer.
+  pub fn binary_expression_clarify_negated_relational(&mut self, value: bool) -> &mut Self {
+    self.insert("binaryExpression.clarifyNegatedRelational", value.into())
+  }
+
+  /// Whether to re-wrap the description paragraphs and tag descriptions of JS doc comments to the line width.
+  ///
+  /// Code fences, Markdown lists, and inline `{@link}` or backtick spans are never broken. The wrapped
+  /// lines of a tag description are indented by two spaces.
+  ///
+  /// * `true` - Reflows the prose, joining short lines that belong to the same paragraph.
+  /// * `false` (default) - Maintains the line breaks as written by the programmer.
+  pub fn js_doc_reflow(&mut self, value: bool) -> &mut Self {
+    self.insert("jsDoc.reflow", value.into())
+  }
+
+  /// Whether to sort the `@deprecated`, `@param`, `@returns`, `@throws`, and `@example` tags
+  /// of JS doc comments in that order. Other tags maintain their position.
+  ///
+  /// Default: `false`
+  pub fn js_doc_sort_tags(&mut self, value: bool) -> &mut Self {
+    self.insert("jsDoc.sortTags", value.into())
+  }
+
+  /// Whether to align the types, names, and descriptions of consecutive `@param` tags in JS doc comments.
+  ///
+  /// * `true` - Ex. `@param {string} a - Description` and `@param {number} bb - Description` are aligned.
+  /// * `false` (default) - Maintains the spacing as written by the programmer.
+  pub fn js_doc_align_params(&mut self, value: bool) -> &mut Self {
+    self.insert("jsDoc.alignParams", value.into())
+  }
+
+  /// Whether to replace JS doc tag aliases with their canonical name (ex. `@return` -> `@returns`).
+  ///
+  /// Default: `false`
+  pub fn js_doc_normalize_tag_aliases(&mut self, value: bool) -> &mut Self {
+    self.insert("jsDoc.normalizeTagAliases", value.into())
+  }
+
+  /// Whether to collapse JS doc comments with a single line of text to `/** text */` when it fits
+  /// within the line width and expand single line JS doc comments to multiple lines when it doesn't.
+  ///
+  /// Default: `false`
+  pub fn js_doc_collapse_single_line(&mut self, value: bool) -> &mut Self {
+    self.insert("jsDoc.collapseSingleLine", value.into())
+  }
+
+  /// Whether to format the code in ts/js/tsx/jsx code fences and `@example` tags of JS doc comments.
+  ///
+  /// Default: `false`
+  pub fn js_doc_format_code(&mut self, value: bool) -> &mut Self {
+    self.insert("jsDoc.formatCode", value.into())
+  }
+
+  /// Whether to wrap paragraphs of consecutive line comments to the comment line width.
+  ///
+  /// Lines that look like code, lists, URLs, directives, or that are indented are left as-is.
+  ///
+  /// * `true` - Wraps the comment lines.
+  /// * `false` (default) - Maintains the comment lines as written.
+  pub fn comment_line_wrap(&mut self, value: bool) -> &mut Self {
+    self.insert("commentLine.wrap", value.into())
+  }
+
+  /// The width of a line to wrap comment text to when `commentLine.wrap` or `jsDoc.reflow` is enabled.
+  ///
+  /// Default: The `lineWidth` value
+  pub fn comments_line_width(&mut self, value: u32) -> &mut Self {
+    self.insert("comments.lineWidth", (value as i32).into())
+  }
+
   /// Whether to force a line per expression when spanning multiple lines.
   ///
   /// * `true` - Formats with each part on a new line.
@@ -445,6 +570,96 @@ impl ConfigurationBuilder {
     self.insert("memberExpression.linePerExpression", value.into())
   }
 
+  /// How to break member expression chains that contain calls.
+  ///
+  /// Default: `MemberChainStyle::Maintain`
+  pub fn member_expression_chain_style(&mut self, value: MemberChainStyle) -> &mut Self {
+    self.insert("memberExpression.chainStyle", value.to_string().into())
+  }
+
+  /// The number of calls after the head of a member chain that may stay on one line
+  /// before each call group is placed on its own line when the chain exceeds the line width
+  /// and using `MemberChainStyle::CallGroups`.
+  ///
+  /// Default: `2`
+  pub fn member_expression_chain_call_threshold(&mut self, value: u32) -> &mut Self {
+    self.insert("memberExpression.chainCallThreshold", (value as i32).into())
+  }
+
+  /// Whether to keep the leading arguments on the same line as the call and "hug" a trailing
+  /// function, object, or array argument (or a leading function argument followed by simple
+  /// arguments) when the arguments exceed the line width.
+  ///
+  /// * `true` - Only the hugged argument may span multiple lines while the other arguments stay
+  ///   on the line of the call. Falls back to an argument per line when hugging doesn't fit.
+  /// * `false` (default) - Allows certain arguments to span multiple lines inline.
+  pub fn arguments_prefer_hugging(&mut self, value: bool) -> &mut Self {
+    self.insert("arguments.preferHugging", value.into())
+  }
+
+  /// Whether to hug a sole object or array pattern parameter (ex. `function Component({ a, b }: Props)`)
+  /// so only its properties break onto separate lines when exceeding the line width.
+  ///
+  /// * `true` - Keeps the pattern's braces on the same lines as the parentheses.
+  /// * `false` (default) - Places the parameter on a separate line like other parameters.
+  pub fn parameters_prefer_hugging(&mut self, value: bool) -> &mut Self {
+    self.insert("parameters.preferHugging", value.into())
+  }
+
+  /// How to format conditional expressions nested in other conditional expressions.
+  ///
+  /// Default: `ConditionalChainStyle::Hanging`
+  pub fn conditional_expression_chain_style(&mut self, value: ConditionalChainStyle) -> &mut Self {
+    self.insert("conditionalExpression.chainStyle", value.to_string().into())
+  }
+
+  /// How to format conditional types nested in other conditional types.
+  ///
+  /// This is independent of `conditional_expression_chain_style`.
+  ///
+  /// Default: `ConditionalChainStyle::Hanging`
+  pub fn conditional_type_chain_style(&mut self, value: ConditionalChainStyle) -> &mut Self {
+    self.insert("conditionalType.chainStyle", value.to_string().into())
+  }
+
+  /// How to lay out assignments and variable declarators that exceed the line width.
+  ///
+  /// Default: `AssignmentLayout::BreakAfterOperator`
+  pub fn assignment_layout(&mut self, value: AssignmentLayout) -> &mut Self {
+    self.insert("assignment.layout", value.to_string().into())
+  }
+
+  /// Whether to surround binary and conditional expressions after `return`, `throw`, and an
+  /// arrow function's `=>` with parentheses when they span multiple lines.
+  ///
+  /// This is similar to `jsx_multi_line_parens`, but for non-JSX expressions.
+  ///
+  /// Default: `false`
+  pub fn expression_multi_line_parens(&mut self, value: bool) -> &mut Self {
+    self.insert("expression.multiLineParens", value.into())
+  }
+
+  /// Whether to fully format simple expressions in template literal placeholders (ex. `${ a+b }`)
+  /// on a single line, removing any parentheses directly inside the placeholder.
+  ///
+  /// The string content of the template literal is never changed.
+  ///
+  /// Default: `false`
+  pub fn template_literal_format_placeholders(&mut self, value: bool) -> &mut Self {
+    self.insert("templateLiteral.formatPlaceholders", value.into())
+  }
+
+  /// Whether to remove parentheses that don't change the meaning of the code based on
+  /// operator precedence (ex. `return (a);` and `(A | B)[][]`).
+  ///
+  /// Parentheses around JSX, object literals in arrow function bodies, comments, and
+  /// `in` expressions in for statement initializers are kept.
+  ///
+  /// Default: `false`
+  pub fn parentheses_remove_redundant(&mut self, value: bool) -> &mut Self {
+    self.insert("parentheses.removeRedundant", value.into())
+  }
+
   /// The kind of separator to use in type literals.
   pub fn type_literal_separator_kind(&mut self, value: SemiColonOrComma) -> &mut Self {
     self.insert("typeLiteral.separatorKind", value.to_string().into())
@@ -455,6 +670,66 @@ impl ConfigurationBuilder {
     self.insert("typeLiteral.separatorKind.singleLine", value.to_string().into())
   }
 
+  /// The case of the `0x`, `0o`, and `0b` prefixes of numeric and big int literals.
+  ///
+  /// Default: `LetterCase::Maintain`
+  pub fn numeric_literal_prefix_case(&mut self, value: LetterCase) -> &mut Self {
+    self.insert("numericLiteral.prefixCase", value.to_string().into())
+  }
+
+  /// The case of the digits of hexadecimal numeric and big int literals (ex. `0xFF` vs `0xff`).
+  ///
+  /// Default: `LetterCase::Maintain`
+  pub fn numeric_literal_digit_case(&mut self, value: LetterCase) -> &mut Self {
+    self.insert("numericLiteral.digitCase", value.to_string().into())
+  }
+
+  /// The case of the exponent of numeric literals (ex. `1e5` vs `1E5`).
+  ///
+  /// Default: `LetterCase::Maintain`
+  pub fn numeric_literal_exponent_case(&mut self, value: LetterCase) -> &mut Self {
+    self.insert("numericLiteral.exponentCase", value.to_string().into())
+  }
+
+  /// Whether to add a zero before the decimal point of numeric literals that start with one.
+  ///
+  /// * `true` - Ex. `.5` becomes `0.5`.
+  /// * `false` (default) - Maintains the literal as written.
+  pub fn numeric_literal_add_leading_zero(&mut self, value: bool) -> &mut Self {
+    self.insert("numericLiteral.addLeadingZero", value.into())
+  }
+
+  /// Whether to remove the trailing zeros of the fraction and a trailing decimal point of numeric literals.
+  ///
+  /// * `true` - Ex. `5.0` and `5.` become `5` and `1.50` becomes `1.5`.
+  /// * `false` (default) - Maintains the literal as written.
+  pub fn numeric_literal_remove_trailing_zeros(&mut self, value: bool) -> &mut Self {
+    self.insert("numericLiteral.removeTrailingZeros", value.into())
+  }
+
+  /// Whether to remove backslashes that don't change the value of a string literal.
+  ///
+  /// * `true` - Ex. `"\d"` becomes `"d"`.
+  /// * `false` (default) - Maintains the escapes as written.
+  pub fn string_literal_remove_unnecessary_escapes(&mut self, value: bool) -> &mut Self {
+    self.insert("stringLiteral.removeUnnecessaryEscapes", value.into())
+  }
+
+  /// The case of the hex digits of `\x` and `\u` escapes in string literals (ex. `"\u00e9"` vs `"\u00E9"`).
+  ///
+  /// Default: `LetterCase::Maintain`
+  pub fn string_literal_unicode_escape_case(&mut self, value: LetterCase) -> &mut Self {
+    self.insert("stringLiteral.unicodeEscapeCase", value.to_string().into())
+  }
+
+  /// Whether to replace `\x` and `\u` escapes of visible characters in string literals with the characters.
+  ///
+  /// * `true` - Ex. `"\u00e9"` becomes `"é"`. Control, whitespace, and invisible characters stay escaped.
+  /// * `false` (default) - Maintains the escapes as written.
+  pub fn string_literal_unescape_printable(&mut self, value: bool) -> &mut Self {
+    self.insert("stringLiteral.unescapePrintable", value.into())
+  }
+
   /// The kind of separator to use in type literals when multi-line.
   pub fn type_literal_separator_kind_multi_line(&mut self, value: SemiColonOrComma) -> &mut Self {
     self.insert("typeLiteral.separatorKind.multiLine", value.to_string().into())
@@ -494,18 +769,64 @@ impl ConfigurationBuilder {
 
   /// The text to use for an ignore comment (ex. `// dprint-ignore`).
   ///
+  /// Multiple aliases may be provided separated by commas (ex. `"dprint-ignore,prettier-ignore"`).
+  ///
   /// Default: `"dprint-ignore"`
   pub fn ignore_node_comment_text(&mut self, value: &str) -> &mut Self {
     self.insert("ignoreNodeCommentText", value.into())
   }
 
+  /// The text to use for a comment that ignores the node beginning on the next line,
+  /// even when the comment is trailing the previous line (ex. `// dprint-ignore-next-line`).
+  ///
+  /// Multiple aliases may be provided separated by commas.
+  ///
+  /// Default: Each ignore node comment text followed by `"-next-line"`
+  pub fn ignore_next_line_comment_text(&mut self, value: &str) -> &mut Self {
+    self.insert("ignoreNextLineCommentText", value.into())
+  }
+
+  /// The text to use for a comment that starts a range of ignored statements,
+  /// members, properties, or JSX children (ex. `// dprint-ignore-start`).
+  ///
+  /// Multiple aliases may be provided separated by commas.
+  ///
+  /// Default: Each ignore node comment text followed by `"-start"`
+  pub fn ignore_start_comment_text(&mut self, value: &str) -> &mut Self {
+    self.insert("ignoreStartCommentText", value.into())
+  }
+
+  /// The text to use for a comment that ends a range of ignored statements,
+  /// members, properties, or JSX children (ex. `// dprint-ignore-end`).
+  ///
+  /// Multiple aliases may be provided separated by commas.
+  ///
+  /// Default: Each ignore node comment text followed by `"-end"`
+  pub fn ignore_end_comment_text(&mut self, value: &str) -> &mut Self {
+    self.insert("ignoreEndCommentText", value.into())
+  }
+
   /// The text to use for a file ignore comment (ex. `// dprint-ignore-file`).
   ///
+  /// Multiple aliases may be provided separated by commas.
+  ///
   /// Default: `"dprint-ignore-file"`
   pub fn ignore_file_comment_text(&mut self, value: &str) -> &mut Self {
     self.insert("ignoreFileCommentText", value.into())
   }
 
+  /* embedded languages */
+
+  /// Comma separated pairs of template literal tags and the file extension to use when
+  /// formatting the template's contents with the host formatter (ex. `"css=css,styled.*=css,gql=graphql"`).
+  ///
+  /// A `*` in a tag matches any text. This only has an effect when formatting with a host.
+  ///
+  /// Default: `""`
+  pub fn tagged_template_file_extensions(&mut self, value: &str) -> &mut Self {
+    self.insert("taggedTemplate.fileExtensions", value.into())
+  }
+
   /* brace position */
 
   pub fn arrow_function_brace_position(&mut self, value: BracePosition) -> &mut Self {
@@ -939,8 +1260,11 @@ mod tests {
       /* common */
       .quote_style(QuoteStyle::AlwaysDouble)
       .jsx_quote_style(JsxQuoteStyle::PreferSingle)
+      .quote_props(QuoteProps::AsNeeded)
       .jsx_multi_line_parens(JsxMultiLineParens::Never)
       .semi_colons(SemiColons::Prefer)
+      .align_trailing_comments(true)
+      .file_header("Copyright")
       .brace_position(BracePosition::NextLine)
       .next_control_flow_position(NextControlFlowPosition::SameLine)
       .operator_position(OperatorPosition::SameLine)
@@ -950,8 +1274,38 @@ mod tests {
       .prefer_hanging(false)
       /* situational */
       .arrow_function_use_parentheses(UseParentheses::Maintain)
+      .arrow_function_line_per_curried_arrow(true)
       .binary_expression_line_per_expression(false)
+      .binary_expression_clarify_mixed_logical(true)
+      .binary_expression_clarify_mixed_bitwise(true)
+      .binary_expression_clarify_negated_relational(true)
       .member_expression_line_per_expression(false)
+      .member_expression_chain_style(MemberChainStyle::CallGroups)
+      .member_expression_chain_call_threshold(3)
+      .arguments_prefer_hugging(true)
+      .parameters_prefer_hugging(true)
+      .conditional_expression_chain_style(ConditionalChainStyle::Flat)
+      .conditional_type_chain_style(ConditionalChainStyle::Flat)
+      .assignment_layout(AssignmentLayout::Auto)
+      .expression_multi_line_parens(true)
+      .template_literal_format_placeholders(true)
+      .js_doc_reflow(true)
+      .js_doc_sort_tags(true)
+      .js_doc_align_params(true)
+      .js_doc_normalize_tag_aliases(true)
+      .js_doc_collapse_single_line(true)
+      .js_doc_format_code(true)
+      .comment_line_wrap(true)
+      .parentheses_remove_redundant(true)
+      .comments_line_width(80)
+      .numeric_literal_prefix_case(LetterCase::Lowercase)
+      .numeric_literal_digit_case(LetterCase::Uppercase)
+      .numeric_literal_exponent_case(LetterCase::Lowercase)
+      .numeric_literal_add_leading_zero(true)
+      .numeric_literal_remove_trailing_zeros(true)
+      .string_literal_remove_unnecessary_escapes(true)
+      .string_literal_unicode_escape_case(LetterCase::Lowercase)
+      .string_literal_unescape_printable(true)
       .type_literal_separator_kind(SemiColonOrComma::Comma)
       .type_literal_separator_kind_single_line(SemiColonOrComma::Comma)
       .type_literal_separator_kind_multi_line(SemiColonOrComma::Comma)
@@ -962,7 +1316,12 @@ mod tests {
       .export_declaration_sort_named_exports(SortOrder::Maintain)
       /* ignore comments */
       .ignore_node_comment_text("ignore")
+      .ignore_next_line_comment_text("ignore-next-line")
+      .ignore_start_comment_text("ignore-start")
+      .ignore_end_comment_text("ignore-end")
       .ignore_file_comment_text("ignore-file")
+      /* embedded languages */
+      .tagged_template_file_extensions("css=css")
       /* brace position*/
       .arrow_function_brace_position(BracePosition::NextLine)
       .class_declaration_brace_position(BracePosition::NextLine)
@@ -1101,7 +1460,7 @@ mod tests {
       .while_statement_space_after_while_keyword(true);
 
     let inner_config = config.get_inner_config();
-    assert_eq!(inner_config.len(), 153);
+    assert_eq!(inner_config.len(), 190);
     let diagnostics = resolve_config(inner_config, &resolve_global_config(HashMap::new(), &Default::default()).config).diagnostics;
     assert_eq!(diagnostics.len(), 0);
   }
@@ -45,14 +45,41 @@ pub fn resolve_config(config: ConfigKeyMap, global_config: &GlobalConfiguration)
   let space_surrounding_properties = get_value(&mut config, "spaceSurroundingProperties", true, &mut diagnostics);
   let type_literal_separator_kind = get_value(&mut config, "typeLiteral.separatorKind", SemiColonOrComma::SemiColon, &mut diagnostics);
   let quote_style = get_value(&mut config, "quoteStyle", QuoteStyle::AlwaysDouble, &mut diagnostics);
+  let line_width = get_value(
+    &mut config,
+    "lineWidth",
+    global_config.line_width.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.line_width),
+    &mut diagnostics,
+  );
+  let ignore_node_comment_texts = get_comment_texts_value(&mut config, "ignoreNodeCommentText", vec![String::from("dprint-ignore")], &mut diagnostics);
+  let (ignore_next_line_comment_text, ignore_next_line_comment_aliases) = split_comment_texts(get_comment_texts_value(
+    &mut config,
+    "ignoreNextLineCommentText",
+    with_suffix(&ignore_node_comment_texts, "-next-line"),
+    &mut diagnostics,
+  ));
+  let (ignore_start_comment_text, ignore_start_comment_aliases) = split_comment_texts(get_comment_texts_value(
+    &mut config,
+    "ignoreStartCommentText",
+    with_suffix(&ignore_node_comment_texts, "-start"),
+    &mut diagnostics,
+  ));
+  let (ignore_end_comment_text, ignore_end_comment_aliases) = split_comment_texts(get_comment_texts_value(
+    &mut config,
+    "ignoreEndCommentText",
+    with_suffix(&ignore_node_comment_texts, "-end"),
+    &mut diagnostics,
+  ));
+  let (ignore_node_comment_text, ignore_node_comment_aliases) = split_comment_texts(ignore_node_comment_texts);
+  let (ignore_file_comment_text, ignore_file_comment_aliases) = split_comment_texts(get_comment_texts_value(
+    &mut config,
+    "ignoreFileCommentText",
+    vec![String::from("dprint-ignore-file")],
+    &mut diagnostics,
+  ));
 
   let resolved_config = Configuration {
-    line_width: get_value(
-      &mut config,
-      "lineWidth",
-      global_config.line_width.unwrap_or(DEFAULT_GLOBAL_CONFIGURATION.line_width),
-      &mut diagnostics,
-    ),
+    line_width,
     use_tabs: get_value(
       &mut config,
       "useTabs",
@@ -72,13 +99,51 @@ pub fn resolve_config(config: ConfigKeyMap, global_config: &GlobalConfiguration)
       &mut diagnostics,
     ),
     quote_style,
+    quote_props: get_value(&mut config, "quoteProps", QuoteProps::Preserve, &mut diagnostics),
     semi_colons,
+    align_trailing_comments: get_value(&mut config, "alignTrailingComments", false, &mut diagnostics),
+    file_header: get_nullable_value::<String>(&mut config, "fileHeader", &mut diagnostics).filter(|header| !header.trim().is_empty()),
     /* situational */
     arrow_function_use_parentheses: get_value(&mut config, "arrowFunction.useParentheses", UseParentheses::Maintain, &mut diagnostics),
+    arrow_function_line_per_curried_arrow: get_value(&mut config, "arrowFunction.linePerCurriedArrow", false, &mut diagnostics),
     binary_expression_line_per_expression: get_value(&mut config, "binaryExpression.linePerExpression", false, &mut diagnostics),
+    binary_expression_clarify_mixed_logical: get_value(&mut config, "binaryExpression.clarifyMixedLogical", false, &mut diagnostics),
+    binary_expression_clarify_mixed_bitwise: get_value(&mut config, "binaryExpression.clarifyMixedBitwise", false, &mut diagnostics),
+    binary_expression_clarify_negated_relational: get_value(&mut config, "binaryExpression.clarifyNegatedRelational", false, &mut diagnostics),
+    js_doc_reflow: get_value(&mut config, "jsDoc.reflow", false, &mut diagnostics),
+    js_doc_sort_tags: get_value(&mut config, "jsDoc.sortTags", false, &mut diagnostics),
+    js_doc_align_params: get_value(&mut config, "jsDoc.alignParams", false, &mut diagnostics),
+    js_doc_normalize_tag_aliases: get_value(&mut config, "jsDoc.normalizeTagAliases", false, &mut diagnostics),
+    js_doc_collapse_single_line: get_value(&mut config, "jsDoc.collapseSingleLine", false, &mut diagnostics),
+    js_doc_format_code: get_value(&mut config, "jsDoc.formatCode", false, &mut diagnostics),
+    comment_line_wrap: get_value(&mut config, "commentLine.wrap", false, &mut diagnostics),
+    comments_line_width: get_value(&mut config, "comments.lineWidth", line_width, &mut diagnostics),
     jsx_quote_style: get_value(&mut config, "jsx.quoteStyle", quote_style.to_jsx_quote_style(), &mut diagnostics),
     jsx_multi_line_parens: get_value(&mut config, "jsx.multiLineParens", JsxMultiLineParens::Prefer, &mut diagnostics),
     member_expression_line_per_expression: get_value(&mut config, "memberExpression.linePerExpression", false, &mut diagnostics),
+    member_expression_chain_style: get_value(&mut config, "memberExpression.chainStyle", MemberChainStyle::Maintain, &mut diagnostics),
+    member_expression_chain_call_threshold: get_value(&mut config, "memberExpression.chainCallThreshold", 2, &mut diagnostics),
+    arguments_prefer_hugging: get_value(&mut config, "arguments.preferHugging", false, &mut diagnostics),
+    parameters_prefer_hugging: get_value(&mut config, "parameters.preferHugging", false, &mut diagnostics),
+    conditional_expression_chain_style: get_value(
+      &mut config,
+      "conditionalExpression.chainStyle",
+      ConditionalChainStyle::Hanging,
+      &mut diagnostics,
+    ),
+    conditional_type_chain_style: get_value(&mut config, "conditionalType.chainStyle", ConditionalChainStyle::Hanging, &mut diagnostics),
+    assignment_layout: get_value(&mut config, "assignment.layout", AssignmentLayout::BreakAfterOperator, &mut diagnostics),
+    expression_multi_line_parens: get_value(&mut config, "expression.multiLineParens", false, &mut diagnostics),
+    template_literal_format_placeholders: get_value(&mut config, "templateLiteral.formatPlaceholders", false, &mut diagnostics),
+    parentheses_remove_redundant: get_value(&mut config, "parentheses.removeRedundant", false, &mut diagnostics),
+    numeric_literal_prefix_case: get_value(&mut config, "numericLiteral.prefixCase", LetterCase::Maintain, &mut diagnostics),
+    numeric_literal_digit_case: get_value(&mut config, "numericLiteral.digitCase", LetterCase::Maintain, &mut diagnostics),
+    numeric_literal_exponent_case: get_value(&mut config, "numericLiteral.exponentCase", LetterCase::Maintain, &mut diagnostics),
+    numeric_literal_add_leading_zero: get_value(&mut config, "numericLiteral.addLeadingZero", false, &mut diagnostics),
+    numeric_literal_remove_trailing_zeros: get_value(&mut config, "numericLiteral.removeTrailingZeros", false, &mut diagnostics),
+    string_literal_remove_unnecessary_escapes: get_value(&mut config, "stringLiteral.removeUnnecessaryEscapes", false, &mut diagnostics),
+    string_literal_unicode_escape_case: get_value(&mut config, "stringLiteral.unicodeEscapeCase", LetterCase::Maintain, &mut diagnostics),
+    string_literal_unescape_printable: get_value(&mut config, "stringLiteral.unescapePrintable", false, &mut diagnostics),
     type_literal_separator_kind_single_line: get_value(
       &mut config,
       "typeLiteral.separatorKind.singleLine",
@@ -97,8 +162,23 @@ pub fn resolve_config(config: ConfigKeyMap, global_config: &GlobalConfiguration)
     import_declaration_sort_named_imports: get_value(&mut config, "importDeclaration.sortNamedImports", SortOrder::CaseInsensitive, &mut diagnostics),
     export_declaration_sort_named_exports: get_value(&mut config, "exportDeclaration.sortNamedExports", SortOrder::CaseInsensitive, &mut diagnostics),
     /* ignore comments */
-    ignore_node_comment_text: get_value(&mut config, "ignoreNodeCommentText", String::from("dprint-ignore"), &mut diagnostics),
-    ignore_file_comment_text: get_value(&mut config, "ignoreFileCommentText", String::from("dprint-ignore-file"), &mut diagnostics),
+    ignore_next_line_comment_text,
+    ignore_next_line_comment_aliases,
+    ignore_start_comment_text,
+    ignore_start_comment_aliases,
+    ignore_end_comment_text,
+    ignore_end_comment_aliases,
+    ignore_node_comment_text,
+    ignore_node_comment_aliases,
+    ignore_file_comment_text,
+    ignore_file_comment_aliases,
+    /* embedded languages */
+    tagged_template_file_extensions: get_tag_file_extensions_value(
+      &mut config,
+      "taggedTemplate.fileExtensions",
+      "",
+      &mut diagnostics,
+    ),
     /* brace position */
     arrow_function_brace_position: get_value(&mut config, "arrowFunction.bracePosition", brace_position, &mut diagnostics),
     class_declaration_brace_position: get_value(&mut config, "classDeclaration.bracePosition", brace_position, &mut diagnostics),
@@ -279,6 +359,65 @@ pub fn resolve_config(config: ConfigKeyMap, global_config: &GlobalConfiguration)
     diagnostics,
   };
 
+  /// Gets a comma separated list of comment texts (ex. `"dprint-ignore, prettier-ignore"`).
+  fn get_comment_texts_value(
+    config: &mut ConfigKeyMap,
+    key: &'static str,
+    default_value: Vec<String>,
+    diagnostics: &mut Vec<ConfigurationDiagnostic>,
+  ) -> Vec<String> {
+    let texts = match get_nullable_value::<String>(config, key, diagnostics) {
+      Some(value) => value
+        .split(',')
+        .map(|text| text.trim())
+        .filter(|text| !text.is_empty())
+        .map(String::from)
+        .collect(),
+      None => Vec::new(),
+    };
+    if texts.is_empty() {
+      default_value
+    } else {
+      texts
+    }
+  }
+
+  /// Splits the comment texts into the first text and its aliases.
+  fn split_comment_texts(mut texts: Vec<String>) -> (String, Vec<String>) {
+    let text = texts.remove(0);
+    (text, texts)
+  }
+
+  /// Gets a comma separated list of tag to file extension pairs (ex. `"css=css, gql=graphql"`).
+  fn get_tag_file_extensions_value(
+    config: &mut ConfigKeyMap,
+    key: &'static str,
+    default_value: &str,
+    diagnostics: &mut Vec<ConfigurationDiagnostic>,
+  ) -> Vec<(String, String)> {
+    let value = get_value(config, key, default_value.to_string(), diagnostics);
+    let mut pairs = Vec::new();
+    for item in value.split(',').map(|item| item.trim()).filter(|item| !item.is_empty()) {
+      match item.split_once('=') {
+        Some((tag, file_extension)) if !tag.trim().is_empty() && !file_extension.trim().is_empty() => {
+          pairs.push((tag.trim().to_string(), file_extension.trim().to_string()));
+        }
+        _ => diagnostics.push(ConfigurationDiagnostic {
+          property_name: String::from(key),
+          message: format!(
+            "Expected a tag and file extension separated by an equals sign (ex. `css=css`), but found: {}",
+            item
+          ),
+        }),
+      }
+    }
+    pairs
+  }
+
+  fn with_suffix(texts: &[String], suffix: &str) -> Vec<String> {
+    texts.iter().map(|text| format!("{}{}", text, suffix)).collect()
+  }
+
   fn fill_deno_config(config: &mut ConfigKeyMap) {
     for (key, value) in ConfigurationBuilder::new().deno().config.iter() {
       if !config.contains_key(key) {
@@ -339,4 +478,43 @@ mod tests {
     assert_eq!(result.config.line_width, expected_config.line_width);
     assert_eq!(result.diagnostics.len(), 0);
   }
+
+  #[test]
+  fn handle_ignore_comment_text_aliases() {
+    let mut config = HashMap::new();
+    config.insert(
+      String::from("ignoreNodeCommentText"),
+      ConfigKeyValue::from_str("dprint-ignore, prettier-ignore"),
+    );
+    let global_config = resolve_global_config(HashMap::new(), &Default::default()).config;
+    let result = resolve_config(config, &global_config);
+    assert_eq!(result.config.ignore_node_comment_text, "dprint-ignore");
+    assert_eq!(result.config.ignore_node_comment_aliases, vec!["prettier-ignore"]);
+    assert_eq!(result.config.ignore_next_line_comment_text, "dprint-ignore-next-line");
+    assert_eq!(result.config.ignore_next_line_comment_aliases, vec!["prettier-ignore-next-line"]);
+    assert_eq!(result.config.ignore_start_comment_text, "dprint-ignore-start");
+    assert_eq!(result.config.ignore_start_comment_aliases, vec!["prettier-ignore-start"]);
+    assert_eq!(result.config.ignore_end_comment_text, "dprint-ignore-end");
+    assert_eq!(result.config.ignore_end_comment_aliases, vec!["prettier-ignore-end"]);
+    assert_eq!(result.config.ignore_file_comment_text, "dprint-ignore-file");
+    assert!(result.config.ignore_file_comment_aliases.is_empty());
+    assert_eq!(result.diagnostics.len(), 0);
+  }
+
+  #[test]
+  fn handle_tagged_template_file_extensions() {
+    let mut config = HashMap::new();
+    config.insert(
+      String::from("taggedTemplate.fileExtensions"),
+      ConfigKeyValue::from_str("css=css, styled.* = css,gql"),
+    );
+    let global_config = resolve_global_config(HashMap::new(), &Default::default()).config;
+    let result = resolve_config(config, &global_config);
+    assert_eq!(
+      result.config.tagged_template_file_extensions,
+      vec![(String::from("css"), String::from("css")), (String::from("styled.*"), String::from("css"))]
+    );
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(result.diagnostics[0].property_name, "taggedTemplate.fileExtensions");
+  }
 }
@@ -253,6 +253,90 @@ generate_str_to_from![
   [CaseInsensitive, "caseInsensitive"]
 ];
 
+/// Whether to quote the keys of properties.
+#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuoteProps {
+  /// Removes the quotes of keys that don't need them.
+  AsNeeded,
+  /// Quotes every key in an object when one of them needs quotes and otherwise removes the quotes.
+  Consistent,
+  /// Maintains the quotes as written by the programmer.
+  Preserve,
+}
+
+generate_str_to_from![QuoteProps, [AsNeeded, "asNeeded"], [Consistent, "consistent"], [Preserve, "preserve"]];
+
+/// Case to use for letters that can be written in either case (ex. hex digits).
+#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LetterCase {
+  /// Maintains the case as written by the programmer.
+  Maintain,
+  /// Forces lowercase letters.
+  Lowercase,
+  /// Forces uppercase letters.
+  Uppercase,
+}
+
+generate_str_to_from![LetterCase, [Maintain, "maintain"], [Lowercase, "lowercase"], [Uppercase, "uppercase"]];
+
+impl LetterCase {
+  /// Gets the text with the letters in this case.
+  pub(crate) fn apply(&self, text: &str) -> String {
+    match self {
+      LetterCase::Maintain => text.to_string(),
+      LetterCase::Lowercase => text.to_ascii_lowercase(),
+      LetterCase::Uppercase => text.to_ascii_uppercase(),
+    }
+  }
+}
+
+/// How to break member expression chains that contain calls.
+#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MemberChainStyle {
+  /// Breaks the chain between any of its parts when it exceeds the line width.
+  Maintain,
+  /// Keeps a short head (ex. `this.foo` or `z.object()`) on the first line and places
+  /// each call group on its own line when a chain with many calls or function arguments
+  /// doesn't fit on one line.
+  CallGroups,
+}
+
+generate_str_to_from![MemberChainStyle, [Maintain, "maintain"], [CallGroups, "callGroups"]];
+
+/// How to format conditional expressions or conditional types nested in other
+/// conditional expressions or conditional types.
+#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConditionalChainStyle {
+  /// Places each nested test or extends check on its own line when spanning multiple lines.
+  Hanging,
+  /// Formats conditionals in the alternate or false type position like an if/else ladder
+  /// with each `: test ? consequent` or `: T extends U ? X` on its own line at the same indentation.
+  Flat,
+  /// Same as `Flat`, but also indents conditionals in the consequent or true type position
+  /// onto their own lines when the parent spans multiple lines.
+  Indent,
+}
+
+generate_str_to_from![ConditionalChainStyle, [Hanging, "hanging"], [Flat, "flat"], [Indent, "indent"]];
+
+/// How to lay out assignments and variable declarators that exceed the line width.
+#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AssignmentLayout {
+  /// Prefers breaking after the operator and indenting the right side.
+  BreakAfterOperator,
+  /// Chooses a layout based on the left and right sides. Calls, objects, arrow functions,
+  /// and awaits are broken inside instead of after the operator, destructuring targets are
+  /// broken first, and chained assignments (ex. `a = b = c`) are placed one per line.
+  Auto,
+}
+
+generate_str_to_from![AssignmentLayout, [BreakAfterOperator, "breakAfterOperator"], [Auto, "auto"]];
+
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Configuration {
@@ -261,18 +345,81 @@ pub struct Configuration {
   pub use_tabs: bool,
   pub new_line_kind: NewLineKind,
   pub quote_style: QuoteStyle,
+  pub quote_props: QuoteProps,
   pub semi_colons: SemiColons,
+  pub align_trailing_comments: bool,
+  pub file_header: Option<String>,
   /* situational */
   #[serde(rename = "arrowFunction.useParentheses")]
   pub arrow_function_use_parentheses: UseParentheses,
+  #[serde(rename = "arrowFunction.linePerCurriedArrow")]
+  pub arrow_function_line_per_curried_arrow: bool,
   #[serde(rename = "binaryExpression.linePerExpression")]
   pub binary_expression_line_per_expression: bool,
+  #[serde(rename = "binaryExpression.clarifyMixedLogical")]
+  pub binary_expression_clarify_mixed_logical: bool,
+  #[serde(rename = "binaryExpression.clarifyMixedBitwise")]
+  pub binary_expression_clarify_mixed_bitwise: bool,
+  #[serde(rename = "binaryExpression.clarifyNegatedRelational")]
+  pub binary_expression_clarify_negated_relational: bool,
+  #[serde(rename = "jsDoc.reflow")]
+  pub js_doc_reflow: bool,
+  #[serde(rename = "jsDoc.sortTags")]
+  pub js_doc_sort_tags: bool,
+  #[serde(rename = "jsDoc.alignParams")]
+  pub js_doc_align_params: bool,
+  #[serde(rename = "jsDoc.normalizeTagAliases")]
+  pub js_doc_normalize_tag_aliases: bool,
+  #[serde(rename = "jsDoc.collapseSingleLine")]
+  pub js_doc_collapse_single_line: bool,
+  #[serde(rename = "jsDoc.formatCode")]
+  pub js_doc_format_code: bool,
+  #[serde(rename = "commentLine.wrap")]
+  pub comment_line_wrap: bool,
+  #[serde(rename = "comments.lineWidth")]
+  pub comments_line_width: u32,
   #[serde(rename = "jsx.quoteStyle")]
   pub jsx_quote_style: JsxQuoteStyle,
   #[serde(rename = "jsx.multiLineParens")]
   pub jsx_multi_line_parens: JsxMultiLineParens,
   #[serde(rename = "memberExpression.linePerExpression")]
   pub member_expression_line_per_expression: bool,
+  #[serde(rename = "memberExpression.chainStyle")]
+  pub member_expression_chain_style: MemberChainStyle,
+  #[serde(rename = "memberExpression.chainCallThreshold")]
+  pub member_expression_chain_call_threshold: u32,
+  #[serde(rename = "arguments.preferHugging")]
+  pub arguments_prefer_hugging: bool,
+  #[serde(rename = "parameters.preferHugging")]
+  pub parameters_prefer_hugging: bool,
+  #[serde(rename = "conditionalExpression.chainStyle")]
+  pub conditional_expression_chain_style: ConditionalChainStyle,
+  #[serde(rename = "conditionalType.chainStyle")]
+  pub conditional_type_chain_style: ConditionalChainStyle,
+  #[serde(rename = "assignment.layout")]
+  pub assignment_layout: AssignmentLayout,
+  #[serde(rename = "expression.multiLineParens")]
+  pub expression_multi_line_parens: bool,
+  #[serde(rename = "templateLiteral.formatPlaceholders")]
+  pub template_literal_format_placeholders: bool,
+  #[serde(rename = "parentheses.removeRedundant")]
+  pub parentheses_remove_redundant: bool,
+  #[serde(rename = "numericLiteral.prefixCase")]
+  pub numeric_literal_prefix_case: LetterCase,
+  #[serde(rename = "numericLiteral.digitCase")]
+  pub numeric_literal_digit_case: LetterCase,
+  #[serde(rename = "numericLiteral.exponentCase")]
+  pub numeric_literal_exponent_case: LetterCase,
+  #[serde(rename = "numericLiteral.addLeadingZero")]
+  pub numeric_literal_add_leading_zero: bool,
+  #[serde(rename = "numericLiteral.removeTrailingZeros")]
+  pub numeric_literal_remove_trailing_zeros: bool,
+  #[serde(rename = "stringLiteral.removeUnnecessaryEscapes")]
+  pub string_literal_remove_unnecessary_escapes: bool,
+  #[serde(rename = "stringLiteral.unicodeEscapeCase")]
+  pub string_literal_unicode_escape_case: LetterCase,
+  #[serde(rename = "stringLiteral.unescapePrintable")]
+  pub string_literal_unescape_printable: bool,
   #[serde(rename = "typeLiteral.separatorKind.singleLine")]
   pub type_literal_separator_kind_single_line: SemiColonOrComma,
   #[serde(rename = "typeLiteral.separatorKind.multiLine")]
@@ -288,7 +435,18 @@ pub struct Configuration {
   pub export_declaration_sort_named_exports: SortOrder,
   /* ignore comments */
   pub ignore_node_comment_text: String,
+  pub ignore_node_comment_aliases: Vec<String>,
+  pub ignore_next_line_comment_text: String,
+  pub ignore_next_line_comment_aliases: Vec<String>,
+  pub ignore_start_comment_text: String,
+  pub ignore_start_comment_aliases: Vec<String>,
+  pub ignore_end_comment_text: String,
+  pub ignore_end_comment_aliases: Vec<String>,
   pub ignore_file_comment_text: String,
+  pub ignore_file_comment_aliases: Vec<String>,
+  /* embedded languages */
+  #[serde(rename = "taggedTemplate.fileExtensions")]
+  pub tagged_template_file_extensions: Vec<(String, String)>,
   /* brace position */
   #[serde(rename = "arrowFunction.bracePosition")]
   pub arrow_function_brace_position: BracePosition,
@@ -1,14 +1,24 @@
 use std::path::Path;
 
+use anyhow::bail;
 use anyhow::Result;
+use deno_ast::swc::common::Span;
 use deno_ast::ParsedSource;
 use dprint_core::configuration::resolve_new_line_kind;
+use dprint_core::configuration::ConfigKeyMap;
+use dprint_core::formatting::utils::string_utils::format_diagnostic;
 use dprint_core::formatting::*;
+use rustc_hash::FxHashSet;
 
 use crate::swc::ensure_no_specific_syntax_errors;
 
 use super::configuration::Configuration;
+use super::generation::file_text_has_directive_comment;
 use super::generation::generate;
+use super::generation::get_ignore_range_scope_diagnostic;
+use super::generation::get_ignore_ranges;
+use super::generation::get_moved_directive_comments;
+use super::generation::FormatWithHost;
 use super::swc::parse_swc_ast;
 
 /// Formats a file.
@@ -39,41 +49,137 @@ use super::swc::parse_swc_ast;
 /// }
 /// ```
 pub fn format_text(file_path: &Path, file_text: &str, config: &Configuration) -> Result<String> {
-  if super::utils::file_text_has_ignore_comment(file_text, &config.ignore_file_comment_text) {
+  if has_ignore_file_comment(file_text, config) {
     Ok(String::from(file_text))
   } else {
     let parsed_source = parse_swc_ast(file_path, file_text)?;
-    inner_format(&parsed_source, config)
+    inner_format(&parsed_source, config, None)
+  }
+}
+
+/// Formats a file using the provided host formatter to format embedded languages
+/// such as CSS, GraphQL, or HTML within tagged templates.
+///
+/// The host formatter is provided a file path with the file extension to format
+/// with (ex. `file.css`), the text to format, and any configuration overrides.
+pub fn format_text_with_host(
+  file_path: &Path,
+  file_text: &str,
+  config: &Configuration,
+  mut format_with_host: impl FnMut(&Path, String, &ConfigKeyMap) -> Result<String>,
+) -> Result<String> {
+  if has_ignore_file_comment(file_text, config) {
+    Ok(String::from(file_text))
+  } else {
+    let parsed_source = parse_swc_ast(file_path, file_text)?;
+    inner_format(&parsed_source, config, Some(&mut format_with_host))
   }
 }
 
 /// Formats an already parsed source. This is useful as a performance optimization.
 pub fn format_parsed_source(source: &ParsedSource, config: &Configuration) -> Result<String> {
-  if super::utils::file_text_has_ignore_comment(source.source().text_str(), &config.ignore_file_comment_text) {
+  if has_ignore_file_comment(source.source().text_str(), config) {
     Ok(source.source().text_str().to_string())
   } else {
-    inner_format(source, config)
+    inner_format(source, config, None)
   }
 }
 
-fn inner_format(parsed_source: &ParsedSource, config: &Configuration) -> Result<String> {
+fn has_ignore_file_comment(file_text: &str, config: &Configuration) -> bool {
+  super::utils::file_text_has_ignore_comment(file_text, &config.ignore_file_comment_text)
+    || config
+      .ignore_file_comment_aliases
+      .iter()
+      .any(|ignore_text| super::utils::file_text_has_ignore_comment(file_text, ignore_text))
+}
+
+fn inner_format(parsed_source: &ParsedSource, config: &Configuration, format_with_host: Option<&mut FormatWithHost>) -> Result<String> {
+  let file_text = parsed_source.source().text_str();
+  let file_text_with_header = config
+    .file_header
+    .as_ref()
+    .and_then(|header| super::utils::get_file_text_with_header(file_text, header));
+  match file_text_with_header {
+    Some(file_text) => {
+      let parsed_source = parse_swc_ast(Path::new(parsed_source.specifier()), &file_text)?;
+      inner_format_parsed_source(&parsed_source, config, format_with_host)
+    }
+    None => inner_format_parsed_source(parsed_source, config, format_with_host),
+  }
+}
+
+fn inner_format_parsed_source(parsed_source: &ParsedSource, config: &Configuration, mut format_with_host: Option<&mut FormatWithHost>) -> Result<String> {
   ensure_no_specific_syntax_errors(parsed_source)?;
+  ensure_balanced_ignore_range_comments(parsed_source, config)?;
+
+  let file_text = parsed_source.source().text_str();
+  let formatted_text = format_with_directive_targets(parsed_source, config, format_with_host.as_deref_mut(), Default::default());
+  if !file_text_has_directive_comment(file_text) {
+    return Ok(formatted_text);
+  }
+
+  // Ensure the line a directive comment applies to (ex. `// @ts-expect-error`) still contains the same
+  // code. When it doesn't, format again keeping the nodes containing those lines as-is and error if
+  // that still doesn't work.
+  let moved_directives = get_moved_directive_comments(parsed_source, &parse_formatted_text(parsed_source, &formatted_text)?);
+  if moved_directives.is_empty() {
+    return Ok(formatted_text);
+  }
+  let directive_target_spans = moved_directives.iter().filter_map(|directive| directive.target_node_span).collect();
+  let formatted_text = format_with_directive_targets(parsed_source, config, format_with_host, directive_target_spans);
+  let moved_directives = get_moved_directive_comments(parsed_source, &parse_formatted_text(parsed_source, &formatted_text)?);
+  if let Some(directive) = moved_directives.first() {
+    let range = (directive.span.lo.0 as usize, directive.span.hi.0 as usize);
+    let message = "Formatting would change the line this directive comment applies to. Restructure the code or add an ignore comment.";
+    bail!("{}", format_diagnostic(Some(range), message, file_text))
+  }
+
+  Ok(formatted_text)
+}
 
-  Ok(dprint_core::formatting::format(
+fn format_with_directive_targets(
+  parsed_source: &ParsedSource,
+  config: &Configuration,
+  format_with_host: Option<&mut FormatWithHost>,
+  directive_target_spans: FxHashSet<Span>,
+) -> String {
+  dprint_core::formatting::format(
     || {
-      let print_items = generate(&parsed_source, config);
+      let print_items = generate(parsed_source, config, format_with_host, directive_target_spans);
       // println!("{}", print_items.get_as_text());
       print_items
     },
     config_to_print_options(parsed_source.source().text_str(), config),
-  ))
+  )
+}
+
+fn parse_formatted_text(parsed_source: &ParsedSource, formatted_text: &str) -> Result<ParsedSource> {
+  parse_swc_ast(Path::new(parsed_source.specifier()), formatted_text)
 }
 
 #[cfg(feature = "tracing")]
 pub fn trace_file(file_path: &Path, file_text: &str, config: &Configuration) -> dprint_core::formatting::TracingResult {
   let parsed_source = parse_swc_ast(file_path, file_text).unwrap();
   ensure_no_specific_syntax_errors(&parsed_source).unwrap();
-  dprint_core::formatting::trace_printing(|| generate(&parsed_source, config), config_to_print_options(file_text, config))
+  ensure_balanced_ignore_range_comments(&parsed_source, config).unwrap();
+  dprint_core::formatting::trace_printing(
+    || generate(&parsed_source, config, None, Default::default()),
+    config_to_print_options(file_text, config),
+  )
+}
+
+fn ensure_balanced_ignore_range_comments(parsed_source: &ParsedSource, config: &Configuration) -> Result<()> {
+  let mut comments = parsed_source.comments().get_vec();
+  comments.sort_by_key(|comment| comment.span.lo);
+  let diagnostic = match get_ignore_ranges(comments.iter(), config) {
+    Ok(ranges) => parsed_source.with_view(|program| get_ignore_range_scope_diagnostic(&ranges, &comments, &program, config)),
+    Err(diagnostic) => Some(diagnostic),
+  };
+  if let Some(diagnostic) = diagnostic {
+    let range = (diagnostic.span.lo.0 as usize, diagnostic.span.hi.0 as usize);
+    bail!("{}", format_diagnostic(Some(range), &diagnostic.message, parsed_source.source().text_str()))
+  }
+  Ok(())
 }
 
 fn config_to_print_options(file_text: &str, config: &Configuration) -> PrintOptions {
@@ -125,6 +231,124 @@ mod test {
     );
   }
 
+  #[test]
+  fn it_should_error_for_ignore_start_comment_without_end() {
+    run_diagnostic_test(
+      "./test.ts",
+      "test;\n// dprint-ignore-start\ntest;",
+      concat!(
+        "Line 2, column 1: Could not find a matching `dprint-ignore-end` comment.\n",
+        "\n",
+        "  // dprint-ignore-start\n",
+        "  ~~~~~~~~~~~~~~~~~~~~~~"
+      ),
+    );
+  }
+
+  #[test]
+  fn it_should_error_for_ignore_end_comment_without_start() {
+    run_diagnostic_test(
+      "./test.ts",
+      "test;\n// dprint-ignore-end\ntest;",
+      concat!(
+        "Line 2, column 1: Could not find a matching `dprint-ignore-start` comment.\n",
+        "\n",
+        "  // dprint-ignore-end\n",
+        "  ~~~~~~~~~~~~~~~~~~~~"
+      ),
+    );
+  }
+
+  #[test]
+  fn it_should_error_for_nested_ignore_start_comments() {
+    run_diagnostic_test(
+      "./test.ts",
+      "// dprint-ignore-start\n/* dprint-ignore-start */\n// dprint-ignore-end",
+      concat!(
+        "Line 2, column 1: Expected a `dprint-ignore-end` comment before another `dprint-ignore-start` comment.\n",
+        "\n",
+        "  /* dprint-ignore-start */\n",
+        "  ~~~~~~~~~~~~~~~~~~~~~~~~~"
+      ),
+    );
+  }
+
+  #[test]
+  fn it_should_error_for_ignore_range_comments_in_different_blocks() {
+    run_diagnostic_test(
+      "./test.ts",
+      "function test() {\n  // dprint-ignore-start\n  test;\n}\n// dprint-ignore-end\n",
+      concat!(
+        "Line 5, column 1: Expected the `dprint-ignore-end` comment to be in the same block as its `dprint-ignore-start` comment.\n",
+        "\n",
+        "  // dprint-ignore-end\n",
+        "  ~~~~~~~~~~~~~~~~~~~~"
+      ),
+    );
+  }
+
+  #[test]
+  fn it_should_error_with_the_comment_alias_used() {
+    let config = ConfigurationBuilder::new().ignore_node_comment_text("dprint-ignore, prettier-ignore").build();
+    let text = "test;\n// prettier-ignore-start\ntest;";
+    assert_eq!(
+      format_text(&PathBuf::from("./test.ts"), text, &config).err().unwrap().to_string(),
+      concat!(
+        "Line 2, column 1: Could not find a matching `prettier-ignore-end` comment.\n",
+        "\n",
+        "  // prettier-ignore-start\n",
+        "  ~~~~~~~~~~~~~~~~~~~~~~~~"
+      ),
+    );
+  }
+
+  #[test]
+  fn it_should_ignore_with_any_of_multiple_comment_aliases() {
+    let config = ConfigurationBuilder::new()
+      .ignore_node_comment_text("dprint-ignore, prettier-ignore, deno-fmt-ignore")
+      .build();
+    let text = concat!(
+      "// prettier-ignore\n",
+      "const a   = [1,   2];\n",
+      "// deno-fmt-ignore\n",
+      "const b   = [1,   2];\n",
+      "// deno-fmt-ignore-next-line\n",
+      "const c   = [1,   2];\n",
+      "// prettier-ignore-start\n",
+      "const d   = [1,   2];\n",
+      "// deno-fmt-ignore-end\n",
+      "const e   = [1,   2];\n",
+    );
+    let expected = concat!(
+      "// prettier-ignore\n",
+      "const a   = [1,   2];\n",
+      "// deno-fmt-ignore\n",
+      "const b   = [1,   2];\n",
+      "// deno-fmt-ignore-next-line\n",
+      "const c   = [1,   2];\n",
+      "// prettier-ignore-start\n",
+      "const d   = [1,   2];\n",
+      "// deno-fmt-ignore-end\n",
+      "const e = [1, 2];\n",
+    );
+    assert_eq!(format_text(&PathBuf::from("./test.ts"), text, &config).unwrap(), expected);
+  }
+
+  #[test]
+  fn it_should_error_when_a_directive_target_line_would_change() {
+    run_diagnostic_test(
+      "./test.ts",
+      "// @ts-ignore\nfirst();   second();\n",
+      concat!(
+        "Line 1, column 1: Formatting would change the line this directive comment applies to. ",
+        "Restructure the code or add an ignore comment.\n",
+        "\n",
+        "  // @ts-ignore\n",
+        "  ~~~~~~~~~~~~~"
+      ),
+    );
+  }
+
   fn run_diagnostic_test(file_path: &str, text: &str, expected: &str) {
     let file_path = PathBuf::from(file_path);
     let parsed_source = crate::swc::parse_swc_ast(&file_path, text).unwrap();
@@ -4,22 +4,57 @@ use deno_ast::swc::common::Span;
 use deno_ast::swc::common::Spanned;
 use deno_ast::swc::parser::token::TokenAndSpan;
 use deno_ast::view::*;
+use dprint_core::configuration::ConfigKeyMap;
 use dprint_core::formatting::{ConditionReference, Info};
 use rustc_hash::FxHashMap;
 use rustc_hash::FxHashSet;
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
 
 use super::*;
 use crate::configuration::*;
 use crate::utils::Stack;
 
+/// Formats text in another language (ex. CSS) using the host formatter.
+pub type FormatWithHost<'a> = dyn FnMut(&Path, String, &ConfigKeyMap) -> anyhow::Result<String> + 'a;
+
+/// Information about the comments that was prepared before generating.
+pub struct PreparedComments {
+  pub js_doc_lines: FxHashMap<BytePos, Vec<String>>,
+  pub wrapped_line_comments: FxHashMap<BytePos, WrappedLineComments>,
+  pub trailing_line_comment_runs: Vec<Vec<(BytePos, u32)>>,
+  /// Spans of the nodes containing the lines that directive comments apply to that should be kept as-is.
+  pub directive_target_spans: FxHashSet<Span>,
+}
+
+/// Information for aligning a trailing line comment with the others in its run.
+#[derive(Clone)]
+pub struct TrailingCommentAlignment {
+  /// The info at the start of the comment and the width of the comment for each generated comment in the run.
+  pub run: Rc<RefCell<Vec<(Info, u32)>>>,
+  /// The info at the start of this comment.
+  pub info: Info,
+  /// The width of this comment.
+  pub width: u32,
+}
+
 pub struct Context<'a> {
   pub is_jsx: bool,
   pub program: &'a Program<'a>,
   pub config: &'a Configuration,
+  pub format_with_host: Option<&'a mut FormatWithHost<'a>>,
   pub comments: CommentTracker<'a>,
   pub token_finder: TokenFinder<'a>,
   pub current_node: Node<'a>,
   pub parent_stack: Stack<Node<'a>>,
+  ignore_ranges: Vec<Span>,
+  formatted_js_doc_lines: FxHashMap<BytePos, Vec<String>>,
+  wrapped_line_comments: FxHashMap<BytePos, WrappedLineComments>,
+  merged_line_comments: FxHashSet<BytePos>,
+  trailing_comment_alignments: FxHashMap<BytePos, TrailingCommentAlignment>,
+  directive_target_spans: FxHashSet<Span>,
+  container_keys_need_quotes: FxHashMap<BytePos, bool>,
   handled_comments: FxHashSet<BytePos>,
   stored_infos: FxHashMap<(BytePos, BytePos), Info>,
   stored_info_ranges: FxHashMap<(BytePos, BytePos), (Info, Info)>,
@@ -27,21 +62,46 @@ pub struct Context<'a> {
   before_comments_start_info_stack: Stack<(Span, Info)>,
   if_stmt_last_brace_condition_ref: Option<ConditionReference>,
   expr_stmt_single_line_parent_brace_ref: Option<ConditionReference>,
+  hugged_pattern_param_pos: Option<BytePos>,
+  single_line_member_chain_pos: Option<BytePos>,
   /// Used for ensuring nodes are parsed in order.
   #[cfg(debug_assertions)]
   pub last_generated_node_pos: u32,
 }
 
 impl<'a> Context<'a> {
-  pub fn new(is_jsx: bool, tokens: &'a [TokenAndSpan], current_node: Node<'a>, program: &'a Program<'a>, config: &'a Configuration) -> Context<'a> {
+  pub fn new(
+    is_jsx: bool,
+    tokens: &'a [TokenAndSpan],
+    current_node: Node<'a>,
+    program: &'a Program<'a>,
+    config: &'a Configuration,
+    format_with_host: Option<&'a mut FormatWithHost<'a>>,
+    prepared_comments: PreparedComments,
+  ) -> Context<'a> {
+    let PreparedComments {
+      js_doc_lines: formatted_js_doc_lines,
+      wrapped_line_comments,
+      trailing_line_comment_runs,
+      directive_target_spans,
+    } = prepared_comments;
+    let merged_line_comments = wrapped_line_comments.values().flat_map(|c| c.merged_comment_starts.iter().copied()).collect();
     Context {
       is_jsx,
       program,
       config,
+      format_with_host,
       comments: CommentTracker::new(program, tokens),
       token_finder: TokenFinder::new(program),
       current_node,
       parent_stack: Stack::new(),
+      ignore_ranges: get_program_ignore_ranges(program, config),
+      formatted_js_doc_lines,
+      wrapped_line_comments,
+      merged_line_comments,
+      trailing_comment_alignments: get_trailing_comment_alignments(trailing_line_comment_runs),
+      directive_target_spans,
+      container_keys_need_quotes: FxHashMap::default(),
       handled_comments: FxHashSet::default(),
       stored_infos: FxHashMap::default(),
       stored_info_ranges: FxHashMap::default(),
@@ -49,6 +109,8 @@ impl<'a> Context<'a> {
       before_comments_start_info_stack: Stack::new(),
       if_stmt_last_brace_condition_ref: None,
       expr_stmt_single_line_parent_brace_ref: None,
+      hugged_pattern_param_pos: None,
+      single_line_member_chain_pos: None,
       #[cfg(debug_assertions)]
       last_generated_node_pos: 0,
     }
@@ -58,6 +120,44 @@ impl<'a> Context<'a> {
     self.parent_stack.peek().unwrap()
   }
 
+  pub fn is_in_ignore_range(&self, span: &Span) -> bool {
+    self.ignore_ranges.iter().any(|range| range.lo <= span.lo && span.hi <= range.hi)
+  }
+
+  pub fn get_formatted_js_doc_lines(&self, comment: &Comment) -> Option<&[String]> {
+    self.formatted_js_doc_lines.get(&comment.lo()).map(|lines| lines.as_slice())
+  }
+
+  /// Takes the wrapped lines of the group of line comments starting with the provided comment,
+  /// marking the other comments in the group as handled.
+  pub fn take_wrapped_line_comments(&mut self, comment: &Comment) -> Option<Vec<String>> {
+    let wrapped_line_comments = self.wrapped_line_comments.remove(&comment.lo())?;
+    self.handled_comments.extend(wrapped_line_comments.merged_comment_starts);
+    Some(wrapped_line_comments.lines)
+  }
+
+  /// Gets if the line comment was merged into the wrapped lines of a previous line comment.
+  pub fn is_merged_line_comment(&self, comment: &Comment) -> bool {
+    self.merged_line_comments.contains(&comment.lo())
+  }
+
+  /// Gets if the node contains the line a directive comment applies to and should be kept as-is.
+  pub fn is_directive_target(&self, node: &Node) -> bool {
+    self.directive_target_spans.contains(&node.span())
+  }
+
+  /// Gets if any of the property keys of the container (ex. an object literal) need quotes, computing it once per container.
+  pub fn get_container_keys_need_quotes(&mut self, container: &Node, compute: impl FnOnce() -> bool) -> bool {
+    *self.container_keys_need_quotes.entry(container.lo()).or_insert_with(compute)
+  }
+
+  /// Takes the alignment of the trailing comment, which adds it to the generated comments of its run.
+  pub fn take_trailing_comment_alignment(&mut self, comment: &Comment) -> Option<TrailingCommentAlignment> {
+    let alignment = self.trailing_comment_alignments.remove(&comment.lo())?;
+    alignment.run.borrow_mut().push((alignment.info, alignment.width));
+    Some(alignment)
+  }
+
   pub fn has_handled_comment(&self, comment: &Comment) -> bool {
     self.handled_comments.contains(&comment.lo())
   }
@@ -98,6 +198,34 @@ impl<'a> Context<'a> {
     self.expr_stmt_single_line_parent_brace_ref.take()
   }
 
+  pub fn store_hugged_pattern_param_pos(&mut self, pos: BytePos) {
+    self.hugged_pattern_param_pos = Some(pos);
+  }
+
+  pub fn store_single_line_member_chain_pos(&mut self, pos: BytePos) {
+    self.single_line_member_chain_pos = Some(pos);
+  }
+
+  /// Gets if the member chain at the position should only break inside its arguments, clearing the stored position when it is.
+  pub fn take_is_single_line_member_chain(&mut self, pos: BytePos) -> bool {
+    if self.single_line_member_chain_pos == Some(pos) {
+      self.single_line_member_chain_pos = None;
+      true
+    } else {
+      false
+    }
+  }
+
+  /// Gets if the pattern at the position is a hugged parameter, clearing the stored position when it is.
+  pub fn take_is_hugged_pattern_param(&mut self, pos: BytePos) -> bool {
+    if self.hugged_pattern_param_pos == Some(pos) {
+      self.hugged_pattern_param_pos = None;
+      true
+    } else {
+      false
+    }
+  }
+
   pub fn get_or_create_current_before_comments_start_info(&mut self) -> Info {
     let current_span = self.current_node.span();
     if let Some((span, info)) = self.before_comments_start_info_stack.peek() {
@@ -142,3 +270,31 @@ impl<'a> Context<'a> {
     }
   }
 }
+
+fn get_program_ignore_ranges(program: &Program, config: &Configuration) -> Vec<Span> {
+  let mut comments = match program.comment_container() {
+    Some(comment_container) => comment_container.all_comments().collect::<Vec<_>>(),
+    None => return Vec::new(),
+  };
+  comments.sort_by_key(|comment| comment.lo());
+  // unbalanced ignore comments are reported before generating, so ignore them here
+  get_ignore_ranges(comments.into_iter(), config).unwrap_or_default()
+}
+
+fn get_trailing_comment_alignments(runs: Vec<Vec<(BytePos, u32)>>) -> FxHashMap<BytePos, TrailingCommentAlignment> {
+  let mut alignments = FxHashMap::default();
+  for run in runs {
+    let generated_run = Rc::new(RefCell::new(Vec::with_capacity(run.len())));
+    for (comment_start, width) in run {
+      alignments.insert(
+        comment_start,
+        TrailingCommentAlignment {
+          run: generated_run.clone(),
+          info: Info::new("trailingCommentStart"),
+          width,
+        },
+      );
+    }
+  }
+  alignments
+}
@@ -0,0 +1,124 @@
+use deno_ast::swc::common::comments::Comment;
+use deno_ast::swc::common::Span;
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::parser::token::Token;
+use deno_ast::swc::parser::token::TokenAndSpan;
+use deno_ast::view::*;
+use deno_ast::ParsedSource;
+
+/// Comment texts of directives that apply to a single line (ex. `// @ts-expect-error`).
+const DIRECTIVE_COMMENT_TEXTS: [&str; 4] = ["@ts-expect-error", "@ts-ignore", "eslint-disable-next-line", "eslint-disable-line"];
+
+pub struct MovedDirectiveComment {
+  /// Span of the directive comment in the original text.
+  pub span: Span,
+  /// Span of the smallest node containing all the code on the line the directive applies to in the original text.
+  pub target_node_span: Option<Span>,
+}
+
+/// Gets if the file text might have a directive comment in order to avoid extra work.
+pub fn file_text_has_directive_comment(file_text: &str) -> bool {
+  DIRECTIVE_COMMENT_TEXTS.iter().any(|text| file_text.contains(text))
+}
+
+/// Gets the directive comments where the line they apply to in the formatted text
+/// no longer contains the code of the line they applied to in the original text.
+pub fn get_moved_directive_comments(original: &ParsedSource, formatted: &ParsedSource) -> Vec<MovedDirectiveComment> {
+  let original_directives = get_directive_comments(original);
+  let formatted_directives = get_directive_comments(formatted);
+  let have_same_directives = original_directives.len() == formatted_directives.len();
+  let mut moved_directives = Vec::new();
+
+  for (i, (comment, target_line_index)) in original_directives.iter().enumerate() {
+    let original_tokens = get_line_tokens(original, *target_line_index);
+    let original_words = get_words(original, original_tokens);
+    let has_moved = if !have_same_directives {
+      true
+    } else if original_words.is_empty() {
+      false
+    } else {
+      let formatted_words = get_words(formatted, get_line_tokens(formatted, formatted_directives[i].1));
+      !formatted_words.windows(original_words.len()).any(|words| words == original_words)
+    };
+
+    if has_moved {
+      moved_directives.push(MovedDirectiveComment {
+        span: comment.span,
+        target_node_span: get_line_node_span(original, original_tokens),
+      });
+    }
+  }
+
+  moved_directives
+}
+
+/// Gets the directive comments along with the index of the line they apply to.
+fn get_directive_comments(parsed_source: &ParsedSource) -> Vec<(Comment, usize)> {
+  let text_info = parsed_source.source();
+  let mut comments = parsed_source.comments().get_vec();
+  comments.sort_by_key(|comment| comment.span.lo);
+
+  comments
+    .into_iter()
+    .filter_map(|comment| {
+      let text = comment.text.trim_start_matches(['/', '*', ' ', '\t']);
+      if text.starts_with("eslint-disable-line") {
+        let line_index = text_info.line_index(comment.span.lo);
+        Some((comment, line_index))
+      } else if DIRECTIVE_COMMENT_TEXTS.iter().any(|directive_text| text.starts_with(directive_text)) {
+        let line_index = text_info.line_index(comment.span.hi) + 1;
+        Some((comment, line_index))
+      } else {
+        None
+      }
+    })
+    .collect()
+}
+
+/// Gets the span of the smallest node that contains all the tokens on the line.
+fn get_line_node_span(parsed_source: &ParsedSource, tokens: &[TokenAndSpan]) -> Option<Span> {
+  let lo = tokens.first()?.span.lo;
+  let hi = tokens.last()?.span.hi;
+  parsed_source.with_view(|program| {
+    let mut node: Node = program.into();
+    while let Some(child) = node.children().into_iter().find(|child| child.lo() <= lo && child.hi() >= hi) {
+      node = child;
+    }
+    if matches!(node.kind(), NodeKind::Module | NodeKind::Script) {
+      None
+    } else {
+      Some(node.span())
+    }
+  })
+}
+
+fn get_line_tokens(parsed_source: &ParsedSource, line_index: usize) -> &[TokenAndSpan] {
+  let text_info = parsed_source.source();
+  let tokens = parsed_source.tokens();
+  if line_index >= text_info.lines_count() {
+    return &[];
+  }
+
+  let line_start = text_info.line_start(line_index);
+  let line_end = text_info.line_end(line_index);
+  let start_index = tokens.partition_point(|token| token.span.lo < line_start);
+  let end_index = tokens.partition_point(|token| token.span.lo < line_end);
+  &tokens[start_index..end_index]
+}
+
+/// Gets the text of the tokens that aren't punctuation, which is what should
+/// remain on the line after formatting.
+fn get_words(parsed_source: &ParsedSource, tokens: &[TokenAndSpan]) -> Vec<String> {
+  let file_text = parsed_source.source().text_str();
+  tokens
+    .iter()
+    .filter_map(|token| match &token.token {
+      Token::Str { value, .. } => Some(value.to_string()),
+      Token::Num(value) => Some(value.to_string()),
+      Token::Word(_) | Token::Regex(..) | Token::BigInt(_) | Token::Template { .. } | Token::JSXName { .. } | Token::JSXText { .. } => {
+        Some(file_text[token.span.lo.0 as usize..token.span.hi.0 as usize].to_string())
+      }
+      _ => None,
+    })
+    .collect()
+}
@@ -4,8 +4,10 @@ use deno_ast::swc::parser::token::{Token, TokenAndSpan};
 use deno_ast::view::*;
 use deno_ast::MediaType;
 use deno_ast::ParsedSource;
+use dprint_core::configuration::ConfigKeyMap;
 use dprint_core::formatting::*;
 use dprint_core::formatting::{condition_resolvers, conditions::*, ir_helpers::*};
+use rustc_hash::FxHashSet;
 use std::rc::Rc;
 
 use super::sorting::*;
@@ -15,14 +17,35 @@ use super::*;
 use crate::configuration::*;
 use crate::utils;
 
-pub fn generate(parsed_source: &ParsedSource, config: &Configuration) -> PrintItems {
+pub fn generate(
+  parsed_source: &ParsedSource,
+  config: &Configuration,
+  format_with_host: Option<&mut FormatWithHost>,
+  directive_target_spans: FxHashSet<Span>,
+) -> PrintItems {
   // println!("Leading: {:?}", parsed_source.comments().leading_map());
   // println!("Trailing: {:?}", parsed_source.comments().trailing_map());
 
+  let prepared_comments = PreparedComments {
+    js_doc_lines: format_js_docs(parsed_source, config),
+    wrapped_line_comments: wrap_line_comments(parsed_source, config),
+    trailing_line_comment_runs: get_trailing_line_comment_runs(parsed_source, config),
+    directive_target_spans,
+  };
+
   parsed_source.with_view(|program| {
     let program_node = program.into();
     let is_jsx = matches!(parsed_source.media_type(), MediaType::Tsx | MediaType::Jsx | MediaType::JavaScript);
-    let mut context = Context::new(is_jsx, parsed_source.tokens(), program_node, &program, config);
+    let format_with_host = format_with_host.map(|format_with_host| format_with_host as &mut FormatWithHost);
+    let mut context = Context::new(
+      is_jsx,
+      parsed_source.tokens(),
+      program_node,
+      &program,
+      config,
+      format_with_host,
+      prepared_comments,
+    );
     let mut items = gen_node(program_node, &mut context);
     items.push_condition(if_true(
       "endOfFileNewLine",
@@ -86,16 +109,20 @@ fn gen_node_with_inner_gen<'a>(node: Node<'a>, context: &mut Context<'a>, inner_
   }
 
   // generate the node
+  let mut ignored_node_trailing_comments = None;
   if has_ignore_comment {
     items.push_str(""); // force the current line indentation
     items.extend(inner_gen(ir_helpers::gen_from_raw_string(node.text_fast(context.program)), context));
 
     // mark any previous comments as handled
-    for comment in context.comments.trailing_comments_with_previous(node_hi) {
+    let trailing_comments = context.comments.trailing_comments_with_previous(node_hi);
+    for comment in trailing_comments.clone() {
       if comment.lo() < node_hi {
         context.mark_comment_handled(comment);
       }
     }
+    // the comments after the node were consumed from the tracker, so keep them for below
+    ignored_node_trailing_comments = Some(trailing_comments);
   } else {
     items.extend(inner_gen(gen_node_inner(node, context), context));
   }
@@ -103,7 +130,10 @@ fn gen_node_with_inner_gen<'a>(node: Node<'a>, context: &mut Context<'a>, inner_
   // Get the trailing comments -- This needs to be done based on the parse
   // stack order because certain nodes like binary expressions are flattened
   if node_hi != parent_hi || matches!(context.parent().kind(), NodeKind::Module | NodeKind::Script) {
-    let trailing_comments = context.comments.trailing_comments_with_previous(node_hi);
+    let trailing_comments = match ignored_node_trailing_comments {
+      Some(trailing_comments) => trailing_comments,
+      None => context.comments.trailing_comments_with_previous(node_hi),
+    };
     items.extend(gen_comments_as_trailing(&node_span, trailing_comments, context));
   }
 
@@ -372,6 +402,10 @@ fn gen_node_with_inner_gen<'a>(node: Node<'a>, context: &mut Context<'a>, inner_
 }
 
 fn get_has_ignore_comment<'a>(leading_comments: &CommentsIterator<'a>, node: &Node<'a>, context: &mut Context<'a>) -> bool {
+  if is_in_ignore_range(node, context) || context.is_directive_target(node) {
+    return true;
+  }
+
   let comments = match node.parent() {
     Some(Node::JSXElement(jsx_element)) => get_comments_for_jsx_children(&jsx_element.children, &node.lo(), context),
     Some(Node::JSXFragment(jsx_fragment)) => get_comments_for_jsx_children(&jsx_fragment.children, &node.lo(), context),
@@ -379,13 +413,29 @@ fn get_has_ignore_comment<'a>(leading_comments: &CommentsIterator<'a>, node: &No
   };
 
   for comment in comments.into_iter() {
-    if ir_helpers::text_has_dprint_ignore(&comment.text, &context.config.ignore_node_comment_text) {
+    // the ignore node comment text is usually a prefix of these other comment texts
+    if is_ignore_next_line_comment(comment, context.config) {
+      if comment.end_line_fast(context.program) + 1 == node.start_line_fast(context.program) {
+        return true;
+      }
+      continue;
+    }
+    if is_ignore_start_comment(comment, context.config) || is_ignore_end_comment(comment, context.config) {
+      continue;
+    }
+    if is_ignore_node_comment(comment, context.config) {
       return true;
     }
   }
 
   return false;
 
+  fn is_in_ignore_range(node: &Node, context: &Context) -> bool {
+    // jsx text is excluded because its surrounding whitespace is not significant
+    let is_range_ignorable = node.parent().map(|parent| is_ignore_range_container(&parent)).unwrap_or(false) && node.kind() != NodeKind::JSXText;
+    is_range_ignorable && context.is_in_ignore_range(&node.span())
+  }
+
   fn get_comments_for_jsx_children<'a>(children: &[JSXElementChild], node_lo: &BytePos, context: &mut Context<'a>) -> CommentsIterator<'a> {
     let mut iterator = CommentsIterator::empty();
     let index = if let Some(index) = children.binary_search_by_key(node_lo, |child| child.lo()).ok() {
@@ -941,7 +991,7 @@ fn gen_enum_decl<'a>(node: &'a TsEnumDecl, context: &mut Context<'a>) -> PrintIt
 
 fn gen_enum_member<'a>(node: &'a TsEnumMember, context: &mut Context<'a>) -> PrintItems {
   let mut items = PrintItems::new();
-  items.extend(gen_node(node.id.into(), context));
+  items.extend(gen_prop_key(node.id.into(), context));
 
   if let Some(init) = &node.init {
     items.extend(gen_assignment(init.into(), "=", context));
@@ -1503,6 +1553,90 @@ fn gen_arrow_func_expr<'a>(node: &'a ArrowExpr, context: &mut Context<'a>) -> Pr
       header_items
     };
 
+    // curried arrow functions (ex. `(a) => (b) => (c) => { ... }`) share the infos of the chain's
+    // headers so they're all kept on one line or each placed on a separate line
+    let mut before_assignment_chain_info = None;
+    let curried_chain_infos = match context.get_info_range_for_node(node) {
+      Some(infos) => Some(infos),
+      None if !context.config.arrow_function_line_per_curried_arrow => None,
+      None => {
+        let inner_arrows = get_curried_inner_arrows(node, context);
+        if inner_arrows.is_empty() {
+          None
+        } else {
+          let infos = (header_start_info, Info::new("endCurriedArrowChainHeaders"));
+          if is_assignment_value(node) {
+            before_assignment_chain_info = Some(Info::new("beforeCurriedArrowChain"));
+          }
+          for inner_arrow in inner_arrows {
+            context.store_info_range_for_node(inner_arrow, infos);
+            if let Some(info) = before_assignment_chain_info {
+              context.store_info_for_node(inner_arrow, info);
+            }
+          }
+          Some(infos)
+        }
+      }
+    };
+    if let Some((chain_start_info, chain_end_info)) = curried_chain_infos {
+      if let BlockStmtOrExpr::Expr(Expr::Arrow(inner_arrow)) = node.body {
+        if context.get_info_range_for_node(inner_arrow).is_some() {
+          let before_chain_info = context.get_info_for_node(inner_arrow);
+          // force re-evaluation of the separator once the end of the chain's headers has been reached
+          items.push_condition(conditions::force_reevaluation_once_resolved(chain_end_info));
+          items.push_condition(if_true_or(
+            "newLineIfMultipleLinesOrMovedCurriedChain",
+            move |context| {
+              // always place each arrow on a separate line once the chain was moved below the assignment
+              if let Some(before_chain_info) = before_chain_info {
+                let before_chain_line_number = context.get_resolved_info(&before_chain_info)?.line_number;
+                if context.get_resolved_info(&chain_start_info)?.line_number > before_chain_line_number {
+                  return Some(true);
+                }
+              }
+              condition_resolvers::is_multiple_lines(context, &chain_start_info, &chain_end_info)
+            },
+            Signal::NewLine.into(),
+            Signal::SpaceOrNewLine.into(),
+          ));
+          let is_chain_head = context.get_info_range_for_node(node).is_none();
+          let generated_inner_arrow = gen_node(inner_arrow.into(), context);
+          let before_chain_info = match before_assignment_chain_info {
+            Some(info) => info,
+            None => {
+              // indent the rest of the chain when it's not moved below an assignment
+              items.extend(if is_chain_head {
+                conditions::indent_if_start_of_line(generated_inner_arrow).into()
+              } else {
+                generated_inner_arrow
+              });
+              return items;
+            }
+          };
+          items.extend(generated_inner_arrow);
+
+          // start the chain on the line after the assignment when its headers span multiple lines
+          let chain_items = items.into_rc_path();
+          let mut items = PrintItems::new();
+          items.push_info(before_chain_info);
+          items.push_condition(conditions::force_reevaluation_once_resolved(chain_end_info));
+          items.push_condition(if_true_or(
+            "newLineIfMultipleLinesCurriedChain",
+            move |context| condition_resolvers::is_multiple_lines(context, &chain_start_info, &chain_end_info),
+            {
+              let mut items = PrintItems::new();
+              items.push_signal(Signal::NewLine);
+              items.extend(with_indent(chain_items.into()));
+              items
+            },
+            chain_items.into(),
+          ));
+          return items;
+        }
+      }
+      items.push_info(chain_end_info);
+    }
+
     let generated_body = gen_node(node.body.into(), context);
     let generated_body = if use_new_line_group_for_arrow_body(node, context) {
       new_line_group(generated_body)
@@ -1554,13 +1688,35 @@ fn gen_arrow_func_expr<'a>(node: &'a ArrowExpr, context: &mut Context<'a>) -> Pr
     items
   }
 
+  /// Gets the arrow functions returned by the arrow function without being wrapped in parentheses
+  /// or having comments (ex. `(b) => ...` and `(c) => ...` in `(a) => (b) => (c) => ...`).
+  fn get_curried_inner_arrows<'a>(node: &'a ArrowExpr, context: &Context<'a>) -> Vec<&'a ArrowExpr<'a>> {
+    let mut inner_arrows = Vec::new();
+    let mut current = node;
+    while let BlockStmtOrExpr::Expr(Expr::Arrow(inner_arrow)) = current.body {
+      if node_helpers::has_surrounding_comments(&inner_arrow.into(), context.program) {
+        break;
+      }
+      inner_arrows.push(inner_arrow);
+      current = inner_arrow;
+    }
+    inner_arrows
+  }
+
+  fn is_assignment_value(node: &ArrowExpr) -> bool {
+    matches!(
+      node.parent().kind(),
+      NodeKind::VarDeclarator | NodeKind::AssignExpr | NodeKind::ClassProp | NodeKind::PrivateProp | NodeKind::KeyValueProp
+    )
+  }
+
   fn should_not_newline_after_arrow(body: &BlockStmtOrExpr, context: &Context) -> bool {
     match body {
       BlockStmtOrExpr::BlockStmt(_) => true,
       BlockStmtOrExpr::Expr(expr) => match expr {
         Expr::Paren(_) | Expr::Array(_) => true,
         Expr::Tpl(tpl) => tpl.quasis[0].raw.value().starts_with(|c: char| c == '\n' || c == '\r'),
-        _ => is_jsx_paren_expr_handled_node(&expr.into(), context),
+        _ => is_jsx_paren_expr_handled_node(&expr.into(), context) || is_multi_line_paren_expr_handled_node(&expr.into(), context),
       },
     }
   }
@@ -1724,6 +1880,10 @@ fn gen_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintIte
             (PrintItems::new(), PrintItems::new())
           };
           let is_inner_binary_expression = bin_expr_item.expr.kind() == NodeKind::BinExpr;
+          let use_clarifying_parens = match bin_expr_item.expr.parent() {
+            Some(Node::BinExpr(parent)) => get_needs_clarifying_parens(parent, &bin_expr_item.expr, context),
+            _ => false,
+          };
           items.extend(gen_node_with_inner_gen(bin_expr_item.expr, context, |node_items, context| {
             let mut items = PrintItems::new();
             if let Some(op) = pre_op {
@@ -1743,6 +1903,15 @@ fn gen_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintIte
               }
             }
 
+            let node_items = if use_clarifying_parens {
+              let mut items = PrintItems::new();
+              items.push_str("(");
+              items.extend(node_items);
+              items.push_str(")");
+              items
+            } else {
+              node_items
+            };
             items.extend(if is_inner_binary_expression {
               let node_items = node_items.into_rc_path();
               with_queued_indent(
@@ -1836,7 +2005,8 @@ fn gen_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintIte
     .items,
   );
 
-  return if node.op().is_equality() { ir_helpers::new_line_group(items) } else { items };
+  let items = if node.op().is_equality() { ir_helpers::new_line_group(items) } else { items };
+  return handle_multi_line_surrounding_parens(node.into(), items, context);
 
   fn get_allow_no_indent(node: &BinExpr) -> bool {
     let parent = node.parent();
@@ -1902,6 +2072,40 @@ fn gen_binary_expr<'a>(node: &'a BinExpr, context: &mut Context<'a>) -> PrintIte
   }
 }
 
+/// Gets if the operand of the binary expression should be surrounded in parentheses to
+/// clarify the order of operations (ex. `a && b || c` to `(a && b) || c`).
+fn get_needs_clarifying_parens(parent: &BinExpr, operand: &Node, context: &Context) -> bool {
+  let parent_op = parent.op();
+  match operand {
+    Node::BinExpr(operand) => {
+      let op = operand.op();
+      let is_bitwise = |op: BinaryOp| op.is_bit_logical() || op.is_bit_shift();
+      let is_arithmetic = |op: BinaryOp| {
+        matches!(
+          op,
+          BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod | BinaryOp::Exp
+        )
+      };
+      if op == parent_op {
+        false
+      } else if parent_op.is_logical() && op.is_logical() {
+        context.config.binary_expression_clarify_mixed_logical
+      } else if is_bitwise(parent_op) && (is_bitwise(op) || is_arithmetic(op)) || is_arithmetic(parent_op) && is_bitwise(op) {
+        context.config.binary_expression_clarify_mixed_bitwise
+      } else {
+        false
+      }
+    }
+    Node::UnaryExpr(operand) => {
+      context.config.binary_expression_clarify_negated_relational
+        && operand.op() == UnaryOp::Bang
+        && matches!(parent_op, BinaryOp::InstanceOf | BinaryOp::In)
+        && parent.left.lo() == operand.lo()
+    }
+    _ => false,
+  }
+}
+
 fn gen_call_expr<'a>(node: &'a CallExpr, context: &mut Context<'a>) -> PrintItems {
   return if node_helpers::is_test_library_call_expr(&node, context.program) {
     gen_test_library_call_expr(node, context)
@@ -2018,9 +2222,11 @@ fn gen_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) -> Pr
     && (node_helpers::get_use_new_lines_for_nodes(&node.test, &node.cons, context.program)
       || node_helpers::get_use_new_lines_for_nodes(&node.cons, &node.alt, context.program));
   let operator_position = get_operator_position(node, &operator_token, context);
-  let top_most_data = get_top_most_data(node, context);
   let before_alternate_info = Info::new("beforeAlternateInfo");
   let end_info = Info::new("endConditionalExpression");
+  let top_most_data = get_top_most_data(node, end_info, context);
+  // when surrounded by multi-line parens, break the whole chain once any of it is multi-line
+  let multi_line_range_end_info = top_most_data.paren_end_info.unwrap_or(before_alternate_info);
   let mut items = PrintItems::new();
 
   if top_most_data.is_top_most {
@@ -2045,12 +2251,15 @@ fn gen_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) -> Pr
     context.end_statement_or_member_infos.peek().copied().unwrap_or(end_info),
   ));
 
-  if force_new_lines {
+  if top_most_data.is_flat_alternate {
+    // keep the test and consequent together like an if/else ladder
+    items.push_signal(Signal::SpaceOrNewLine);
+  } else if force_new_lines {
     items.push_signal(Signal::NewLine);
   } else {
     items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(
       top_most_data.top_most_info,
-      Some(before_alternate_info),
+      Some(multi_line_range_end_info),
     ));
   }
 
@@ -2086,7 +2295,7 @@ fn gen_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) -> Pr
     } else {
       items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(
         top_most_data.top_most_info,
-        Some(before_alternate_info),
+        Some(multi_line_range_end_info),
       ));
     }
 
@@ -2106,7 +2315,7 @@ fn gen_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) -> Pr
     items
   };
 
-  if top_most_data.is_top_most {
+  if top_most_data.is_top_most || top_most_data.is_indented_consequent {
     items.push_condition(conditions::indent_if_start_of_line(cons_and_alt_items));
   } else {
     let cons_and_alt_items = cons_and_alt_items.into_rc_path();
@@ -2126,21 +2335,30 @@ fn gen_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) -> Pr
     ));
   }
 
-  return items;
+  return handle_multi_line_surrounding_parens(node.into(), items, context);
 
   struct TopMostData {
     top_most_info: Info,
     is_top_most: bool,
+    /// If this is in the alternate position of a parent formatted as a flat chain.
+    is_flat_alternate: bool,
+    /// If this is in the consequent position of a parent formatted with indented consequents.
+    is_indented_consequent: bool,
+    /// The end of the top most node when it's surrounded by multi-line parens.
+    paren_end_info: Option<Info>,
   }
 
-  fn get_top_most_data(node: &CondExpr, context: &mut Context) -> TopMostData {
+  fn get_top_most_data(node: &CondExpr, end_info: Info, context: &mut Context) -> TopMostData {
     // The "top most" node in nested conditionals follows the ancestors up through
-    // the alternate expressions.
+    // the alternate expressions (and consequent expressions when indenting them).
+    let chain_style = context.config.conditional_expression_chain_style;
     let mut top_most_node = node;
 
     for ancestor in context.parent_stack.iter() {
       if let Node::CondExpr(parent) = ancestor {
-        if parent.alt.lo() == top_most_node.lo() {
+        let is_alt = parent.alt.lo() == top_most_node.lo();
+        let is_cons = parent.cons.lo() == top_most_node.lo();
+        if is_alt || is_cons && chain_style == ConditionalChainStyle::Indent {
           top_most_node = parent;
         } else {
           break;
@@ -2152,8 +2370,31 @@ fn gen_conditional_expr<'a>(node: &'a CondExpr, context: &mut Context<'a>) -> Pr
 
     let is_top_most = top_most_node.span() == node.span();
     let top_most_info = get_or_set_top_most_info(top_most_node.lo(), is_top_most, context);
+    let paren_end_info = if is_top_most {
+      if is_multi_line_paren_expr_handled_node(&node.into(), context) {
+        context.store_info_for_node(node, end_info);
+        Some(end_info)
+      } else {
+        None
+      }
+    } else {
+      context.get_info_for_node(top_most_node)
+    };
+    let (is_flat_alternate, is_indented_consequent) = match context.parent_stack.iter().next() {
+      Some(Node::CondExpr(parent)) if !is_top_most => (
+        chain_style != ConditionalChainStyle::Hanging && parent.alt.lo() == node.lo(),
+        chain_style == ConditionalChainStyle::Indent && parent.cons.lo() == node.lo(),
+      ),
+      _ => (false, false),
+    };
 
-    return TopMostData { is_top_most, top_most_info };
+    return TopMostData {
+      is_top_most,
+      top_most_info,
+      is_flat_alternate,
+      is_indented_consequent,
+      paren_end_info,
+    };
 
     fn get_or_set_top_most_info(top_most_expr_start: BytePos, is_top_most: bool, context: &mut Context) -> Info {
       if is_top_most {
@@ -2294,11 +2535,81 @@ fn gen_getter_prop<'a>(node: &'a GetterProp, context: &mut Context<'a>) -> Print
 
 fn gen_key_value_prop<'a>(node: &'a KeyValueProp, context: &mut Context<'a>) -> PrintItems {
   let mut items = PrintItems::new();
-  items.extend(gen_node(node.key.into(), context));
+  items.extend(gen_prop_key(node.key.into(), context));
   items.extend(gen_assignment(node.value.into(), ":", context));
   items
 }
 
+/// Generates the key of a key value property, property signature, or enum member
+/// adding or removing its quotes based on the configuration.
+fn gen_prop_key<'a>(key: Node<'a>, context: &mut Context<'a>) -> PrintItems {
+  if context.config.quote_props == QuoteProps::Preserve {
+    return gen_node(key, context);
+  }
+  let container = match key.parent().and_then(|parent| parent.parent()) {
+    Some(container) => container,
+    None => return gen_node(key, context),
+  };
+  let allow_numbers = container.kind() == NodeKind::ObjectLit;
+  let use_quotes = context.config.quote_props == QuoteProps::Consistent
+    && context.get_container_keys_need_quotes(&container, || {
+      get_container_keys(container).into_iter().any(|key| match key {
+        Node::Str(str) => get_unquoted_key_text(str, allow_numbers, context.program).is_none(),
+        _ => false,
+      })
+    });
+
+  let new_text = match key {
+    Node::Str(str) if !use_quotes => get_unquoted_key_text(str, allow_numbers, context.program).map(|text| text.to_string()),
+    Node::Ident(_) if use_quotes => Some(format!("{0}{1}{0}", get_quote_char(context), key.text_fast(context.program))),
+    Node::Number(_) if use_quotes && is_simple_number(key.text_fast(context.program)) => {
+      Some(format!("{0}{1}{0}", get_quote_char(context), key.text_fast(context.program)))
+    }
+    _ => None,
+  };
+  return match new_text {
+    Some(new_text) => gen_node_with_inner_gen(key, context, |_, _| new_text.into()),
+    None => gen_node(key, context),
+  };
+
+  fn get_container_keys(container: Node) -> Vec<Node> {
+    container
+      .children()
+      .into_iter()
+      .filter_map(|child| match child {
+        Node::KeyValueProp(prop) => Some(prop.key.into()),
+        Node::TsPropertySignature(prop) if !prop.computed() => Some(prop.key.into()),
+        Node::TsEnumMember(member) => Some(member.id.into()),
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// Gets the text of the string key without quotes when that doesn't change its meaning.
+  fn get_unquoted_key_text<'a>(node: &Str, allow_numbers: bool, program: &Program<'a>) -> Option<&'a str> {
+    let raw_text = node.text_fast(program);
+    let text = &raw_text[1..raw_text.len() - 1];
+    if is_identifier(text) || allow_numbers && is_simple_number(text) {
+      Some(text)
+    } else {
+      None
+    }
+  }
+
+  fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+      Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$'),
+      _ => false,
+    }
+  }
+
+  /// Gets if the text is an integer that's written the same way when converted to a string.
+  fn is_simple_number(text: &str) -> bool {
+    (text == "0" || !text.starts_with('0')) && !text.is_empty() && text.len() <= 15 && text.chars().all(|c| c.is_ascii_digit())
+  }
+}
+
 fn gen_assign_prop<'a>(node: &'a AssignProp, context: &mut Context<'a>) -> PrintItems {
   // assignment properties are not valid, so turn this into a key value property
   let mut items = PrintItems::new();
@@ -2436,6 +2747,26 @@ fn should_skip_paren_expr(node: &ParenExpr, context: &Context) -> bool {
     return true;
   }
 
+  if context.config.parentheses_remove_redundant && is_redundant_paren_expr(node) {
+    // keep the parentheses that would be added back to clarify the order of operations
+    let is_clarifying = match node.parent() {
+      Node::BinExpr(parent) => {
+        let mut inner_expr = node.expr;
+        while let Expr::Paren(paren_expr) = inner_expr {
+          inner_expr = paren_expr.expr;
+        }
+        get_needs_clarifying_parens(parent, &inner_expr.into(), context)
+      }
+      _ => false,
+    };
+    return !is_clarifying;
+  }
+
+  // skip over any paren exprs directly in a template literal placeholder
+  if context.config.template_literal_format_placeholders && node.parent().kind() == NodeKind::Tpl {
+    return true;
+  }
+
   // skip over any paren exprs within paren exprs and needless paren exprs
   let parent = node.parent();
   if matches!(
@@ -2474,8 +2805,8 @@ fn should_skip_paren_expr(node: &ParenExpr, context: &Context) -> bool {
   }
 
   // skip explicitly parsing this as a paren expr as that will be handled
-  // in the JSX element/fragment and it might collapse back to not having a paren expr
-  is_jsx_paren_expr_handled_node(&node.expr.into(), context)
+  // in the JSX element/fragment or expression and it might collapse back to not having a paren expr
+  is_jsx_paren_expr_handled_node(&node.expr.into(), context) || is_multi_line_paren_expr_handled_node(&node.expr.into(), context)
 }
 
 fn gen_sequence_expr<'a>(node: &'a SeqExpr, context: &mut Context<'a>) -> PrintItems {
@@ -2492,6 +2823,7 @@ fn gen_sequence_expr<'a>(node: &'a SeqExpr, context: &mut Context<'a>) -> PrintI
       multi_line_options: ir_helpers::MultiLineOptions::same_line_start_hanging_indent(),
       force_possible_newline_at_start: false,
       node_sorter: None,
+      hugged_index: None,
     },
     context,
   )
@@ -2551,6 +2883,14 @@ fn gen_tagged_tpl<'a>(node: &'a TaggedTpl, context: &mut Context<'a>) -> PrintIt
 }
 
 fn gen_tpl<'a>(node: &'a Tpl, context: &mut Context<'a>) -> PrintItems {
+  if let Node::TaggedTpl(tagged_tpl) = node.parent() {
+    if context.format_with_host.is_some() {
+      if let Some(items) = gen_tpl_with_host(tagged_tpl, node, context) {
+        return items;
+      }
+    }
+  }
+
   gen_template_literal(
     node.quasis.iter().map(|&n| n.into()).collect(),
     node.exprs.iter().map(|x| x.into()).collect(),
@@ -2558,6 +2898,124 @@ fn gen_tpl<'a>(node: &'a Tpl, context: &mut Context<'a>) -> PrintItems {
   )
 }
 
+/// Formats the contents of a tagged template in another language (ex. CSS) using the host formatter.
+/// Returns `None` when the template should be formatted as a regular template literal.
+fn gen_tpl_with_host<'a>(tagged_tpl: &'a TaggedTpl, node: &'a Tpl, context: &mut Context<'a>) -> Option<PrintItems> {
+  const PLACEHOLDER_PREFIX: &str = "dprint_placeholder_";
+
+  let file_extension = get_file_extension(tagged_tpl, context)?;
+  let expr_texts = get_expr_texts(node, context)?;
+  let mut text = String::new();
+  for (i, quasi) in node.quasis.iter().enumerate() {
+    text.push_str(quasi.text_fast(context.program));
+    if i < expr_texts.len() {
+      text.push_str(&get_placeholder(i));
+    }
+  }
+  // don't bother handling escapes, text that looks like a placeholder, or templates that have nothing to format
+  if text.trim().is_empty() || text.contains('\\') || node.quasis.iter().any(|quasi| quasi.text_fast(context.program).contains(PLACEHOLDER_PREFIX)) {
+    return None;
+  }
+
+  let file_path = std::path::PathBuf::from(format!("file.{}", file_extension));
+  let host_config = get_host_config(node, context);
+  let format_with_host = context.format_with_host.as_mut()?;
+  let formatted_text = format_with_host(&file_path, text.clone(), &host_config).ok()?;
+  // keep the template as-is when the host didn't change it (ex. no plugin for the file extension)
+  if formatted_text == text {
+    return None;
+  }
+  let formatted_text = restore_placeholders(formatted_text.trim_end(), &expr_texts)?;
+  let formatted_text = formatted_text.trim_start_matches(['\r', '\n']);
+
+  // the expressions are printed as-is, so mark any comments within them as handled
+  for comment in context.comments.trailing_comments_with_previous(node.hi()) {
+    if comment.lo() < node.hi() {
+      context.mark_comment_handled(comment);
+    }
+  }
+
+  let mut items = PrintItems::new();
+  items.push_str("`");
+  if !formatted_text.is_empty() {
+    items.push_signal(Signal::NewLine);
+    items.extend(ir_helpers::with_indent(ir_helpers::gen_from_string(formatted_text)));
+    items.push_signal(Signal::NewLine);
+  }
+  items.push_str("`");
+  return Some(items);
+
+  /// Gets the configuration for the host, where the line width excludes the indentation the template's text will have.
+  fn get_host_config(node: &Tpl, context: &Context) -> ConfigKeyMap {
+    let config = context.config;
+    let indent_level = node_helpers::get_indent_level(node.lo(), context.program) + 1;
+    let line_width = config.line_width.saturating_sub(indent_level * config.indent_width as u32);
+    let mut host_config = ConfigKeyMap::new();
+    host_config.insert(String::from("lineWidth"), (line_width as i32).into());
+    host_config.insert(String::from("indentWidth"), (config.indent_width as i32).into());
+    host_config.insert(String::from("useTabs"), config.use_tabs.into());
+    host_config
+  }
+
+  fn get_file_extension<'a>(tagged_tpl: &TaggedTpl, context: &Context<'a>) -> Option<&'a str> {
+    let tag_text = tagged_tpl
+      .tag
+      .text_fast(context.program)
+      .chars()
+      .filter(|c| !c.is_whitespace())
+      .collect::<String>();
+    context
+      .config
+      .tagged_template_file_extensions
+      .iter()
+      .find(|(tag, _)| utils::is_wildcard_match(&tag_text, tag))
+      .map(|(_, file_extension)| file_extension.as_str())
+  }
+
+  fn get_expr_texts<'a>(node: &'a Tpl, context: &Context<'a>) -> Option<Vec<&'a str>> {
+    let mut texts = Vec::with_capacity(node.exprs.len());
+    for (i, expr) in node.exprs.iter().enumerate() {
+      let expr_text = expr.text_fast(context.program);
+      // keep it simple and only handle expressions on a single line without surrounding comments
+      let text_before = Span::new(node.quasis[i].hi(), expr.lo(), Default::default()).text_fast(context.program);
+      let text_after = Span::new(expr.hi(), node.quasis[i + 1].lo(), Default::default()).text_fast(context.program);
+      if expr_text.contains('\n') || text_before.trim() != "${" || text_after.trim() != "}" {
+        return None;
+      }
+      texts.push(expr_text);
+    }
+    Some(texts)
+  }
+
+  fn restore_placeholders(formatted_text: &str, expr_texts: &[&str]) -> Option<String> {
+    if formatted_text.contains('`') || formatted_text.contains("${") {
+      return None;
+    }
+
+    let mut result = String::new();
+    let mut remaining = formatted_text;
+    for (i, expr_text) in expr_texts.iter().enumerate() {
+      // the placeholders must remain in the same order so that the expressions are evaluated in the same order
+      let placeholder = get_placeholder(i);
+      let index = remaining.find(&placeholder)?;
+      result.push_str(&remaining[..index]);
+      result.push_str("${");
+      result.push_str(expr_text);
+      result.push('}');
+      remaining = &remaining[index + placeholder.len()..];
+    }
+    if remaining.contains(PLACEHOLDER_PREFIX) {
+      return None;
+    }
+    result.push_str(remaining);
+    Some(result)
+  }
+
+  fn get_placeholder(index: usize) -> String {
+    format!("{}{}_", PLACEHOLDER_PREFIX, index)
+  }
+}
+
 fn gen_tpl_element<'a>(node: &'a TplElement, context: &mut Context<'a>) -> PrintItems {
   gen_from_raw_string(node.text_fast(context.program))
 }
@@ -2572,7 +3030,8 @@ fn gen_template_literal<'a>(quasis: Vec<Node<'a>>, exprs: Vec<Node<'a>>, context
     } else {
       items.push_str("${");
       items.push_signal(Signal::FinishIgnoringIndent);
-      let keep_on_one_line = get_keep_on_one_line(&node);
+      let keep_on_one_line =
+        get_keep_on_one_line(&node) || context.config.template_literal_format_placeholders && is_simple_placeholder_expr(&node, context.program);
       let possible_surround_newlines = get_possible_surround_newlines(&node);
       let generated_expr = gen_node(node, context);
       items.extend(if keep_on_one_line {
@@ -2635,6 +3094,27 @@ fn gen_template_literal<'a>(quasis: Vec<Node<'a>>, exprs: Vec<Node<'a>>, context
     }
   }
 
+  /// Gets if the placeholder expression can be formatted on a single line without
+  /// losing comments or collapsing something like a function body or a multi-line expression.
+  fn is_simple_placeholder_expr(node: &Node, program: &Program) -> bool {
+    if node.start_line_fast(program) != node.end_line_fast(program) {
+      return false;
+    }
+    let has_comments = node
+      .tokens_fast(program)
+      .iter()
+      .any(|token| !token.span.lo.leading_comments_fast(program).is_empty() || !token.span.hi.trailing_comments_fast(program).is_empty());
+    !has_comments && is_simple_expr(node, program)
+  }
+
+  fn is_simple_expr(node: &Node, program: &Program) -> bool {
+    match node {
+      Node::ArrowExpr(_) | Node::FnExpr(_) | Node::ClassExpr(_) | Node::ObjectLit(_) | Node::JSXElement(_) | Node::JSXFragment(_) => false,
+      Node::TplElement(element) => !element.text_fast(program).contains('\n'),
+      _ => node.children().iter().all(|child| is_simple_expr(child, program)),
+    }
+  }
+
   fn get_possible_surround_newlines(node: &Node) -> bool {
     match node {
       Node::CondExpr(_) => true,
@@ -2988,7 +3468,7 @@ fn gen_property_signature<'a>(node: &'a TsPropertySignature, context: &mut Conte
       context,
     )
   } else {
-    gen_node(node.key.into(), context)
+    gen_prop_key(node.key.into(), context)
   });
 
   if node.optional() {
@@ -3103,6 +3583,55 @@ fn handle_jsx_surrounding_parens<'a>(inner_items: PrintItems, context: &mut Cont
     return surround_with_newlines_indented_if_multi_line(inner_items, context.config.indent_width);
   }
 
+  return surround_with_parens_indented_if_multi_line(inner_items);
+
+  fn should_jsx_surround_newlines(node: &Node, context: &Context) -> bool {
+    let mut parent = node.parent().unwrap();
+    while let Some(paren_expr) = parent.to::<ParenExpr>() {
+      if node_helpers::has_surrounding_comments(&paren_expr.expr.into(), context.program) {
+        return false;
+      }
+      parent = parent.parent().unwrap();
+    }
+
+    parent.is::<JSXExprContainer>()
+  }
+}
+
+fn handle_multi_line_surrounding_parens(node: Node, inner_items: PrintItems, context: &Context) -> PrintItems {
+  if is_multi_line_paren_expr_handled_node(&node, context) {
+    surround_with_parens_indented_if_multi_line(inner_items)
+  } else {
+    inner_items
+  }
+}
+
+/// Gets if the binary or conditional expression is surrounded by parentheses when it spans multiple lines.
+fn is_multi_line_paren_expr_handled_node(node: &Node, context: &Context) -> bool {
+  if !context.config.expression_multi_line_parens {
+    return false;
+  }
+
+  if !matches!(node.kind(), NodeKind::BinExpr | NodeKind::CondExpr) {
+    return false;
+  }
+
+  if node_helpers::has_surrounding_comments(node, context.program) {
+    return false;
+  }
+
+  let mut parent = node.parent().unwrap();
+  while parent.is::<ParenExpr>() {
+    if node_helpers::has_surrounding_comments(&parent, context.program) {
+      return false;
+    }
+    parent = parent.parent().unwrap();
+  }
+
+  matches!(parent.kind(), NodeKind::ReturnStmt | NodeKind::ThrowStmt | NodeKind::ArrowExpr)
+}
+
+fn surround_with_parens_indented_if_multi_line(inner_items: PrintItems) -> PrintItems {
   let start_info = Info::new("conditionalParenStartInfo");
   let end_info = Info::new("conditionalParenEndInfo");
   let mut items = PrintItems::new();
@@ -3128,19 +3657,7 @@ fn handle_jsx_surrounding_parens<'a>(inner_items: PrintItems, context: &mut Cont
   ));
 
   items.push_info(end_info);
-  return items;
-
-  fn should_jsx_surround_newlines(node: &Node, context: &Context) -> bool {
-    let mut parent = node.parent().unwrap();
-    while let Some(paren_expr) = parent.to::<ParenExpr>() {
-      if node_helpers::has_surrounding_comments(&paren_expr.expr.into(), context.program) {
-        return false;
-      }
-      parent = parent.parent().unwrap();
-    }
-
-    parent.is::<JSXExprContainer>()
-  }
+  items
 }
 
 fn is_jsx_paren_expr_handled_node(node: &Node, context: &Context) -> bool {
@@ -3328,6 +3845,7 @@ fn gen_jsx_opening_element<'a>(node: &'a JSXOpeningElement, context: &mut Contex
         multi_line_options: ir_helpers::MultiLineOptions::surround_newlines_indented(),
         force_possible_newline_at_start: false,
         node_sorter: None,
+        hugged_index: None,
       },
       context,
     ));
@@ -3457,7 +3975,7 @@ fn gen_jsx_text<'a>(node: &'a JSXText, context: &mut Context<'a>) -> PrintItems
 /* literals */
 
 fn gen_big_int_literal<'a>(node: &'a BigInt, context: &mut Context<'a>) -> PrintItems {
-  node.text_fast(context.program).to_string().into()
+  get_numeric_literal_text(node.text_fast(context.program), context.config).into()
 }
 
 fn gen_bool_literal(node: &Bool) -> PrintItems {
@@ -3469,7 +3987,15 @@ fn gen_bool_literal(node: &Bool) -> PrintItems {
 }
 
 fn gen_num_literal<'a>(node: &'a Number, context: &mut Context<'a>) -> PrintItems {
-  node.text_fast(context.program).to_string().into()
+  let text = node.text_fast(context.program);
+  let normalized_text = get_numeric_literal_text(text, context.config);
+  // keep the decimal point of the object of a member expression (ex. `5.0.toString()`)
+  let is_member_obj = matches!(node.parent(), Node::MemberExpr(member_expr) if member_expr.obj.lo() == node.lo());
+  if is_member_obj && normalized_text.chars().all(|c| c.is_ascii_digit() || c == '_') && text.contains('.') {
+    text.to_string().into()
+  } else {
+    normalized_text.into()
+  }
 }
 
 fn gen_reg_exp_literal(node: &Regex, _: &mut Context) -> PrintItems {
@@ -3483,11 +4009,13 @@ fn gen_reg_exp_literal(node: &Regex, _: &mut Context) -> PrintItems {
 }
 
 fn gen_string_literal<'a>(node: &'a Str, context: &mut Context<'a>) -> PrintItems {
-  return gen_from_raw_string(&get_string_literal_text(
-    get_string_value(&node, context),
-    node.parent().is::<JSXAttr>(),
-    context,
-  ));
+  let is_jsx_attribute = node.parent().is::<JSXAttr>();
+  let mut string_value = get_string_value(&node, context);
+  // JSX attributes don't have escapes and changing the escapes of a directive (ex. `"use strict"`) may change its meaning
+  if !is_jsx_attribute && !node.parent().is::<ExprStmt>() {
+    string_value = get_string_text_with_normalized_escapes(&string_value, context.config);
+  }
+  return gen_from_raw_string(&get_string_literal_text(string_value, is_jsx_attribute, context));
 
   fn get_string_literal_text(string_value: String, is_jsx_attribute: bool, context: &mut Context) -> String {
     return if is_jsx_attribute {
@@ -3648,10 +4176,20 @@ fn gen_array_pat<'a>(node: &'a ArrayPat, context: &mut Context<'a>) -> PrintItem
   if node.optional() {
     items.push_str("?");
   }
-  items.extend(gen_type_ann_with_colon_if_exists(&node.type_ann, context));
+  items.extend(gen_pattern_type_ann(node.into(), &node.type_ann, context));
   items
 }
 
+fn gen_pattern_type_ann<'a>(node: Node<'a>, type_ann: &Option<&TsTypeAnn<'a>>, context: &mut Context<'a>) -> PrintItems {
+  let items = gen_type_ann_with_colon_if_exists(type_ann, context);
+  if context.take_is_hugged_pattern_param(node.lo()) {
+    // prefer breaking the properties of a hugged pattern over its type annotation
+    new_line_group(items)
+  } else {
+    items
+  }
+}
+
 fn gen_assign_pat<'a>(node: &'a AssignPat, context: &mut Context<'a>) -> PrintItems {
   let mut items = PrintItems::new();
   items.extend(gen_node(node.left.into(), context));
@@ -3701,7 +4239,7 @@ fn gen_object_pat<'a>(node: &'a ObjectPat, context: &mut Context<'a>) -> PrintIt
   if node.optional() {
     items.push_str("?");
   }
-  items.extend(gen_type_ann_with_colon_if_exists(&node.type_ann, context));
+  items.extend(gen_pattern_type_ann(node.into(), &node.type_ann, context));
   return items;
 
   fn get_trailing_commas(node: &ObjectPat, context: &Context) -> TrailingCommas {
@@ -4731,6 +5269,7 @@ fn gen_var_decl<'a>(node: &'a VarDecl, context: &mut Context<'a>) -> PrintItems
         multi_line_options: ir_helpers::MultiLineOptions::same_line_start_hanging_indent(),
         force_possible_newline_at_start: false,
         node_sorter: None,
+        hugged_index: None,
       },
       context,
     ));
@@ -4838,8 +5377,16 @@ fn gen_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context<'
     !context.config.conditional_type_prefer_single_line && node_helpers::get_use_new_lines_for_nodes(&node.true_type, &node.false_type, context.program);
   let top_most_data = get_top_most_data(node, context);
   let is_parent_conditional_type = node.parent().kind() == NodeKind::TsConditionalType;
+  let is_hanging = context.config.conditional_type_chain_style == ConditionalChainStyle::Hanging;
+  // in a flat chain, the branches of nested conditionals in the false type align with the top most
+  let indent_branches = if is_hanging {
+    true
+  } else {
+    top_most_data.is_top_most || top_most_data.is_indented_true_type
+  };
   let mut items = PrintItems::new();
   let before_false_info = Info::new("beforeFalse");
+  let end_info = Info::new("endConditionalType");
 
   // main area
   items.extend(ir_helpers::new_line_group(gen_node(node.check_type.into(), context)));
@@ -4854,13 +5401,27 @@ fn gen_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context<'
     node.extends_type.into(),
     context,
   ))));
-  items.push_signal(Signal::SpaceOrNewLine);
-  items.push_condition(conditions::indent_if_start_of_line({
+  if is_hanging {
+    items.push_signal(Signal::SpaceOrNewLine);
+  } else {
+    // force re-evaluation of the conditions below once the end info has been reached
+    items.push_condition(conditions::force_reevaluation_once_resolved(end_info));
+    items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(
+      top_most_data.top_most_info,
+      Some(before_false_info),
+    ));
+  }
+  let true_type_generated = {
     let mut items = PrintItems::new();
     items.push_str("? ");
     items.extend(ir_helpers::new_line_group(gen_node(node.true_type.into(), context)));
     items
-  }));
+  };
+  if indent_branches {
+    items.push_condition(conditions::indent_if_start_of_line(true_type_generated));
+  } else {
+    items.extend(true_type_generated);
+  }
 
   // false type
   if use_new_lines {
@@ -4880,28 +5441,34 @@ fn gen_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context<'
     items
   };
 
-  if is_parent_conditional_type {
+  if is_hanging && is_parent_conditional_type || !indent_branches {
     items.extend(false_type_generated);
   } else {
     items.push_condition(conditions::indent_if_start_of_line(false_type_generated));
   }
+  items.push_info(end_info);
 
   return items;
 
   struct TopMostData {
     top_most_info: Info,
     is_top_most: bool,
+    /// If this is in the true type of a parent formatted with indented true types.
+    is_indented_true_type: bool,
   }
 
   fn get_top_most_data(node: &TsConditionalType, context: &mut Context) -> TopMostData {
     // todo: consolidate with conditional expression
     // The "top most" node in nested conditionals follows the ancestors up through
-    // the false expressions.
+    // the false expressions (and true expressions when indenting them).
+    let chain_style = context.config.conditional_type_chain_style;
     let mut top_most_node = node;
 
     for ancestor in context.parent_stack.iter() {
       if let Node::TsConditionalType(parent) = ancestor {
-        if parent.false_type.lo() == top_most_node.lo() {
+        let is_false_type = parent.false_type.lo() == top_most_node.lo();
+        let is_true_type = parent.true_type.lo() == top_most_node.lo();
+        if is_false_type || is_true_type && chain_style == ConditionalChainStyle::Indent {
           top_most_node = parent;
         } else {
           break;
@@ -4913,8 +5480,16 @@ fn gen_conditional_type<'a>(node: &'a TsConditionalType, context: &mut Context<'
 
     let is_top_most = top_most_node.span() == node.span();
     let top_most_info = get_or_set_top_most_info(top_most_node.lo(), is_top_most, context);
+    let is_indented_true_type = match context.parent_stack.iter().next() {
+      Some(Node::TsConditionalType(parent)) => !is_top_most && chain_style == ConditionalChainStyle::Indent && parent.true_type.lo() == node.lo(),
+      _ => false,
+    };
 
-    return TopMostData { is_top_most, top_most_info };
+    return TopMostData {
+      is_top_most,
+      top_most_info,
+      is_indented_true_type,
+    };
 
     fn get_or_set_top_most_info(top_most_expr_start: BytePos, is_top_most: bool, context: &mut Context) -> Info {
       if is_top_most {
@@ -5244,6 +5819,13 @@ fn gen_qualified_name<'a>(node: &'a TsQualifiedName, context: &mut Context<'a>)
 }
 
 fn gen_parenthesized_type<'a>(node: &'a TsParenthesizedType, context: &mut Context<'a>) -> PrintItems {
+  if context.config.parentheses_remove_redundant
+    && !node_helpers::has_surrounding_comments(&node.type_ann.into(), context.program)
+    && is_redundant_paren_type(node)
+  {
+    return gen_node(node.type_ann.into(), context);
+  }
+
   let generated_type = conditions::with_indent_if_start_of_line_indented(gen_node_in_parens(
     |context| gen_node(node.type_ann.into(), context),
     GenNodeInParensOptions {
@@ -5354,6 +5936,7 @@ fn gen_type_parameters<'a>(node: TypeParamNode<'a>, context: &mut Context<'a>) -
       multi_line_options: ir_helpers::MultiLineOptions::surround_newlines_indented(),
       force_possible_newline_at_start: false,
       node_sorter: None,
+      hugged_index: None,
     },
     context,
   ));
@@ -5474,6 +6057,8 @@ fn gen_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, context
   let indent_width = context.config.indent_width;
   let prefer_hanging = context.config.union_and_intersection_type_prefer_hanging;
   let is_parent_union_or_intersection = matches!(node.node.parent().unwrap().kind(), NodeKind::TsUnionType | NodeKind::TsIntersectionType);
+  // place each type on a separate line for the right side of a type alias
+  let is_type_alias_chain = context.config.assignment_layout == AssignmentLayout::Auto && node.node.parent().unwrap().kind() == NodeKind::TsTypeAliasDecl;
   let multi_line_options = if !is_parent_union_or_intersection {
     if use_surround_newlines(&node.node) {
       ir_helpers::MultiLineOptions::surround_newlines_indented()
@@ -5495,7 +6080,10 @@ fn gen_union_or_intersection_type<'a>(node: UnionOrIntersectionType<'a>, context
       for (i, type_node) in node.types.iter().enumerate() {
         let (allow_inline_multi_line, allow_inline_single_line) = {
           let is_last_value = i + 1 == types_count; // allow the last type to be single line
-          (allows_inline_multi_line(&type_node.into(), context, types_count > 1), is_last_value)
+          (
+            !is_type_alias_chain && allows_inline_multi_line(&type_node.into(), context, types_count > 1),
+            is_last_value,
+          )
         };
         let separator_token = context.token_finder.get_previous_token_if_operator(&type_node.span(), separator);
         let start_info = Info::new("startInfo");
@@ -5628,6 +6216,8 @@ fn gen_comments_as_statements<'a>(comments: impl Iterator<Item = &'a Comment>, l
         context,
       ));
       last_node = Some(comment);
+    } else if context.is_merged_line_comment(comment) {
+      last_node = Some(comment);
     }
   }
   items
@@ -5694,6 +6284,8 @@ fn gen_comment_collection<'a>(
         context,
       ));
       last_node = Some(comment);
+    } else if context.is_merged_line_comment(comment) {
+      last_node = Some(comment);
     }
   }
   items
@@ -5751,73 +6343,135 @@ fn gen_comment(comment: &Comment, context: &mut Context) -> Option<PrintItems> {
   // mark handled and generate
   context.mark_comment_handled(comment);
 
-  return Some(match comment.kind {
+  Some(match comment.kind {
     CommentKind::Block => {
-      if is_js_doc(&comment.text) {
+      if is_js_doc(&comment.text) || context.get_formatted_js_doc_lines(comment).is_some() {
         gen_js_doc(comment, context)
       } else {
-        ir_helpers::gen_js_like_comment_block(&comment.text)
+        gen_block_comment(comment, context)
       }
     }
-    CommentKind::Line => ir_helpers::gen_js_like_comment_line(&comment.text, context.config.comment_line_force_space_after_slashes),
-  });
-
-  fn is_js_doc(text: &str) -> bool {
-    // be strict about what a js doc is for now
-    if text.starts_with("*") && text.contains("\n") {
-      for line in text.trim().split("\n").skip(1) {
-        let first_non_whitespace = line.trim_start().chars().next();
-        if !matches!(first_non_whitespace, Some('*')) {
-          return false;
-        }
+    CommentKind::Line => {
+      let mut items = PrintItems::new();
+      if let Some(alignment) = context.take_trailing_comment_alignment(comment) {
+        items.extend(gen_trailing_comment_alignment(alignment, context));
       }
+      items.extend(match context.take_wrapped_line_comments(comment) {
+        Some(lines) => gen_wrapped_line_comments(&lines, context),
+        None => ir_helpers::gen_js_like_comment_line(&comment.text, context.config.comment_line_force_space_after_slashes),
+      });
+      items
+    }
+  })
+}
 
-      true
+/// Pads a trailing line comment to the column of the other trailing line comments in its run.
+fn gen_trailing_comment_alignment(alignment: TrailingCommentAlignment, context: &Context) -> PrintItems {
+  let mut items = PrintItems::new();
+  let line_width = context.config.line_width;
+  items.push_info(alignment.info);
+
+  // add the padding in decreasing powers of two to allow for any amount of padding
+  let mut padding = 1;
+  while padding * 2 <= line_width {
+    padding *= 2;
+  }
+  while padding > 0 {
+    let alignment = alignment.clone();
+    items.push_condition(if_true(
+      "alignTrailingComment",
+      move |condition_context| {
+        let column_number = get_column_number(condition_context, &alignment, line_width)?;
+        Some(condition_context.writer_info.column_number + padding <= column_number)
+      },
+      " ".repeat(padding as usize).into(),
+    ));
+    padding /= 2;
+  }
+
+  return items;
+
+  fn get_column_number(condition_context: &mut ConditionResolverContext, alignment: &TrailingCommentAlignment, line_width: u32) -> Option<u32> {
+    let mut column_number = 0;
+    let mut widths = Vec::new();
+    let mut is_aligned = false;
+    for (info, width) in alignment.run.borrow().iter() {
+      let resolved_info = condition_context.get_resolved_info(info)?;
+      // comments that ended up on their own line are not aligned
+      if !resolved_info.is_start_of_line() {
+        column_number = std::cmp::max(column_number, resolved_info.column_number);
+        widths.push(*width);
+        is_aligned = is_aligned || *info == alignment.info;
+      }
+    }
+    // don't align the run when any of its comments would exceed the line width at the aligned column
+    if !is_aligned || widths.iter().any(|width| column_number + width > line_width) {
+      Some(0)
     } else {
-      false
+      Some(column_number)
     }
   }
 }
 
-fn gen_js_doc(comment: &Comment, _context: &mut Context) -> PrintItems {
-  return lines_to_print_items(build_lines(comment));
-
-  fn build_lines(comment: &Comment) -> Vec<&str> {
-    let mut lines: Vec<&str> = Vec::new();
-
-    for line in utils::split_lines(&comment.text) {
-      let line = line[get_line_start_index(line)..].trim_end();
-      if !line.is_empty() || !lines.last().map(|l| l.is_empty()).unwrap_or(false) {
-        lines.push(line);
-      }
+fn gen_wrapped_line_comments(lines: &[String], context: &Context) -> PrintItems {
+  let mut items = PrintItems::new();
+  for (i, line) in lines.iter().enumerate() {
+    if i > 0 {
+      items.push_signal(Signal::NewLine);
     }
+    items.extend(ir_helpers::gen_js_like_comment_line(
+      line,
+      context.config.comment_line_force_space_after_slashes,
+    ));
+  }
+  items
+}
 
-    lines
+fn gen_block_comment(comment: &Comment, context: &mut Context) -> PrintItems {
+  // For comments on their own line, shift the lines after the first by the change in indentation
+  // so they stay aligned. This is only possible when all of them are indented at least as much
+  // as the comment in the original text.
+  if !node_helpers::is_first_node_on_line(comment, context.program) {
+    return ir_helpers::gen_js_like_comment_block(&comment.text);
+  }
+  let indent_text = node_helpers::get_line_indent_text(comment.lo(), context.program);
+  let lines = utils::split_lines(&comment.text).collect::<Vec<_>>();
+  let last_index = lines.len() - 1;
+  let can_reindent = lines
+    .iter()
+    .enumerate()
+    .skip(1)
+    .all(|(i, line)| line.starts_with(indent_text) || i < last_index && line.trim().is_empty());
+  if lines.len() == 1 || !can_reindent {
+    return ir_helpers::gen_js_like_comment_block(&comment.text);
   }
 
-  fn get_line_start_index(text: &str) -> usize {
-    let mut chars = text.char_indices();
-    while let Some((byte_index, c)) = chars.next() {
-      if c == '*' {
-        if matches!(chars.next(), Some((_, ' '))) {
-          return byte_index + 2;
-        } else {
-          return byte_index + 1;
-        }
-      } else if !c.is_whitespace() {
-        return byte_index;
-      }
+  let mut items = PrintItems::new();
+  items.push_str("/*");
+  for (i, line) in lines.into_iter().enumerate() {
+    let line = if i == 0 { line } else { line.strip_prefix(indent_text).unwrap_or("") };
+    if i > 0 {
+      items.push_signal(Signal::NewLine);
     }
-
-    0
+    // keep the whitespace before the closing `*/`
+    items.extend(gen_from_string(if i == last_index { line } else { line.trim_end() }));
   }
+  items.push_str("*/");
+  items
+}
+
+fn gen_js_doc(comment: &Comment, context: &mut Context) -> PrintItems {
+  return match context.get_formatted_js_doc_lines(comment) {
+    Some(lines) => lines_to_print_items(lines),
+    None => lines_to_print_items(&get_js_doc_lines(&comment.text)),
+  };
 
-  fn lines_to_print_items(lines: Vec<&str>) -> PrintItems {
+  fn lines_to_print_items(lines: &[impl AsRef<str>]) -> PrintItems {
     let mut items = PrintItems::new();
 
     items.push_str("/*");
 
-    for (i, line) in lines.iter().enumerate() {
+    for (i, line) in lines.iter().map(|l| l.as_ref()).enumerate() {
       if i > 0 {
         items.push_signal(Signal::NewLine);
       }
@@ -5832,7 +6486,7 @@ fn gen_js_doc(comment: &Comment, _context: &mut Context) -> PrintItems {
       }
     }
 
-    if lines.len() > 1 && lines.last().map(|l| l.is_empty()).unwrap_or(false) {
+    if lines.len() > 1 && lines.last().map(|l| l.as_ref().is_empty()).unwrap_or(false) {
       items.push_str("/");
     } else {
       items.push_str(" */");
@@ -5989,6 +6643,7 @@ fn gen_array_like_nodes<'a>(opts: GenArrayLikeNodesOptions<'a>, context: &mut Co
           multi_line_options: ir_helpers::MultiLineOptions::surround_newlines_indented(),
           force_possible_newline_at_start: false,
           node_sorter: None,
+          hugged_index: None,
         },
         context,
       )
@@ -6404,12 +7059,23 @@ where
     context.config.arguments_prefer_hanging
   };
   let trailing_commas = get_trailing_commas(&opts.node, &nodes, is_parameters, context);
+  let is_hugged_pattern_param = is_parameters && context.config.parameters_prefer_hugging && nodes.len() == 1 && is_pattern_param(&nodes[0]);
+  let hugged_index = if !is_parameters && !prefer_hanging && context.config.arguments_prefer_hugging {
+    get_hugged_index(&nodes)
+  } else {
+    None
+  };
 
   return gen_surrounded_by_tokens(
     |context| {
       let mut items = PrintItems::new();
 
-      if !force_use_new_lines && nodes.len() == 1 && is_arrow_function_with_expr_body(&nodes[0]) {
+      if is_hugged_pattern_param {
+        // the pattern's braces stay beside the parens and only its properties break
+        let node = nodes.into_iter().next().unwrap();
+        context.store_hugged_pattern_param_pos(get_param_pat(&node).lo());
+        items.extend(gen_node(node, context));
+      } else if !force_use_new_lines && nodes.len() == 1 && is_arrow_function_with_expr_body(&nodes[0]) {
         let start_info = Info::new("startArrow");
         let generated_node = gen_node(nodes.into_iter().next().unwrap(), context);
 
@@ -6440,6 +7106,7 @@ where
             multi_line_options: ir_helpers::MultiLineOptions::surround_newlines_indented(),
             force_possible_newline_at_start: is_parameters,
             node_sorter: None,
+            hugged_index,
           },
           context,
         ));
@@ -6459,6 +7126,83 @@ where
     context,
   );
 
+  fn is_pattern_param(node: &Node) -> bool {
+    matches!(get_param_pat(node), Node::ObjectPat(_) | Node::ArrayPat(_))
+  }
+
+  fn get_param_pat<'a>(node: &Node<'a>) -> Node<'a> {
+    match node {
+      Node::Param(param) => param.pat.into(),
+      _ => *node,
+    }
+  }
+
+  /// Gets the index of the argument to hug, which is a trailing function, object, or array
+  /// (ex. `foo(a, b, { ... })`) or a leading function followed by simple arguments (ex. `setTimeout(() => { ... }, 500)`).
+  fn get_hugged_index(nodes: &[Node]) -> Option<usize> {
+    let is_first_block_function = match get_arg_expr(nodes.first()?) {
+      Some(Expr::Fn(_)) => true,
+      Some(Expr::Arrow(arrow_expr)) => matches!(arrow_expr.body, BlockStmtOrExpr::BlockStmt(_)),
+      _ => false,
+    };
+    if is_first_block_function && nodes.iter().skip(1).all(is_simple_arg) {
+      return Some(0);
+    }
+
+    let last_index = nodes.len() - 1;
+    let last_kind = get_huggable_kind(&nodes[last_index])?;
+    // don't hug when the argument before is similar (ex. `foo({ ... }, { ... })`)
+    let penultimate_kind = last_index.checked_sub(1).and_then(|index| get_huggable_kind(&nodes[index]));
+    if penultimate_kind != Some(last_kind) {
+      Some(last_index)
+    } else {
+      None
+    }
+  }
+
+  /// Gets if the argument is short and can't be hugged itself (ex. `500` or a dependency array like `[a, b.c]`).
+  fn is_simple_arg(node: &Node) -> bool {
+    return match get_arg_expr(node) {
+      Some(Expr::Array(array_lit)) => array_lit
+        .elems
+        .iter()
+        .all(|elem| elem.as_ref().map(|elem| elem.spread().is_none() && is_simple_expr(&elem.expr)).unwrap_or(true)),
+      Some(expr) => get_huggable_kind(node).is_none() && !matches!(expr, Expr::Cond(_)),
+      None => false,
+    };
+
+    fn is_simple_expr(expr: &Expr) -> bool {
+      match expr {
+        Expr::Ident(_) | Expr::This(_) | Expr::Lit(_) => true,
+        Expr::Member(member_expr) => is_simple_expr(&member_expr.obj),
+        _ => false,
+      }
+    }
+  }
+
+  #[derive(Clone, Copy, PartialEq)]
+  enum HuggableKind {
+    Function,
+    Object,
+    Array,
+  }
+
+  fn get_huggable_kind(node: &Node) -> Option<HuggableKind> {
+    match get_arg_expr(node)? {
+      Expr::Fn(_) | Expr::Arrow(_) => Some(HuggableKind::Function),
+      Expr::Object(_) => Some(HuggableKind::Object),
+      Expr::Array(_) => Some(HuggableKind::Array),
+      _ => None,
+    }
+  }
+
+  fn get_arg_expr<'a>(node: &Node<'a>) -> Option<Expr<'a>> {
+    match node {
+      Node::ExprOrSpread(expr_or_spread) if expr_or_spread.spread().is_none() => Some(expr_or_spread.expr),
+      _ => None,
+    }
+  }
+
   fn get_trailing_commas(node: &Node, nodes: &[Node], is_parameters: bool, context: &mut Context) -> TrailingCommas {
     if let Some(last) = nodes.last() {
       // this would be a syntax error
@@ -6647,6 +7391,8 @@ struct GenSeparatedValuesParams<'a> {
   multi_line_options: ir_helpers::MultiLineOptions,
   force_possible_newline_at_start: bool,
   node_sorter: Option<Box<dyn Fn((usize, Option<&Node<'a>>), (usize, Option<&Node<'a>>), &Program<'a>) -> std::cmp::Ordering>>,
+  /// The index of the only value allowed to be multi-line while staying inline with the other values.
+  hugged_index: Option<usize>,
 }
 
 enum NodeOrSeparator<'a> {
@@ -6683,6 +7429,7 @@ fn gen_separated_values_with_result<'a>(opts: GenSeparatedValuesParams<'a>, cont
   let indent_width = context.config.indent_width;
   let compute_lines_span = opts.allow_blank_lines; // save time otherwise
   let node_sorter = opts.node_sorter;
+  let hugged_index = opts.hugged_index;
 
   // would need to make this take into account the new position of the nodes
   #[cfg(debug_assertions)]
@@ -6703,8 +7450,14 @@ fn gen_separated_values_with_result<'a>(opts: GenSeparatedValuesParams<'a>, cont
           None => i,
         };
         let (allow_inline_multi_line, allow_inline_single_line) = if let NodeOrSeparator::Node(value) = &value {
-          let is_last_value = node_index + 1 == nodes_count; // allow the last node to be single line
-          (allows_inline_multi_line(value, context, nodes_count > 1), is_last_value)
+          match hugged_index {
+            // allow the values around the hugged value to be single line
+            Some(hugged_index) => (node_index == hugged_index, true),
+            None => {
+              let is_last_value = node_index + 1 == nodes_count; // allow the last node to be single line
+              (allows_inline_multi_line(value, context, nodes_count > 1), is_last_value)
+            }
+          }
         } else {
           (false, false)
         };
@@ -6817,10 +7570,10 @@ fn gen_node_with_separator<'a>(value: Node<'a>, generated_separator: PrintItems,
     PrintItems::new()
   };
 
-  // if the current node is ignored and already has a semi-colon, then skip adding a separator
-  let is_ignored_with_semi_colon =
-    value.text_fast(context.program).ends_with(';') && get_has_ignore_comment(&value.leading_comments_fast(context.program), &value, context);
-  if is_ignored_with_semi_colon {
+  // if the current node is ignored and already has a separator (ex. a type member's semi-colon or comma), then skip adding one
+  let is_ignored_with_separator =
+    value.text_fast(context.program).ends_with([';', ',']) && get_has_ignore_comment(&value.leading_comments_fast(context.program), &value, context);
+  if is_ignored_with_separator {
     items.extend(gen_node(value, context));
   } else {
     let generated_separator = generated_separator.into_rc_path();
@@ -7020,6 +7773,7 @@ fn gen_extends_or_implements<'a>(opts: GenExtendsOrImplementsOptions<'a>, contex
         multi_line_options: ir_helpers::MultiLineOptions::new_line_start(),
         force_possible_newline_at_start: false,
         node_sorter: None,
+        hugged_index: None,
       },
       context,
     ));
@@ -7073,6 +7827,7 @@ fn gen_object_like_node<'a>(opts: GenObjectLikeNodeOptions<'a>, context: &mut Co
             multi_line_options: ir_helpers::MultiLineOptions::surround_newlines_indented(),
             force_possible_newline_at_start: false,
             node_sorter: opts.node_sorter,
+            hugged_index: None,
           },
           context,
         )
@@ -7153,14 +7908,33 @@ fn gen_for_flattened_member_like_expr<'a>(node: FlattenedMemberLikeExpr<'a>, con
     items.push_info(member_expr_start_info);
   }
 
+  let call_group_starts = get_call_group_starts(&node.nodes, context);
+  let call_groups_end_info = Info::new("callGroupsEnd");
+  let is_single_line = context.take_is_single_line_member_chain(node.node.lo());
+
   items.extend(gen_for_member_like_expr_item(&node.nodes[0], context, 0, total_items_len));
 
   for (i, item) in node.nodes.iter().enumerate().skip(1) {
     let force_use_new_line =
       !context.config.member_expression_prefer_single_line && node_helpers::get_use_new_lines_for_nodes(&node.nodes[i - 1], &node.nodes[i], context.program);
-    if item.is_optional() || !item.is_computed() {
+    if !call_group_starts.is_empty() {
+      // keep the parts of a call group together and place each group on its own line when the chain is multi-line
+      if call_group_starts.contains(&i) {
+        if i == call_group_starts[0] {
+          items.push_condition(conditions::force_reevaluation_once_resolved(call_groups_end_info));
+        }
+        items.push_condition(if_true_or(
+          "isMultipleLinesCallGroups",
+          move |context| condition_resolvers::is_multiple_lines(context, &member_expr_start_info, &call_groups_end_info),
+          Signal::NewLine.into(),
+          Signal::PossibleNewLine.into(),
+        ));
+      }
+    } else if item.is_optional() || !item.is_computed() {
       if force_use_new_line {
         items.push_signal(Signal::NewLine);
+      } else if is_single_line {
+        // break within the arguments instead
       } else if !context.config.member_expression_line_per_expression {
         items.push_condition(conditions::if_above_width(context.config.indent_width, Signal::PossibleNewLine.into()));
       } else {
@@ -7187,7 +7961,83 @@ fn gen_for_flattened_member_like_expr<'a>(node: FlattenedMemberLikeExpr<'a>, con
     }
   }
 
-  items
+  if !call_group_starts.is_empty() {
+    items.push_info(call_groups_end_info);
+  }
+
+  return items;
+
+  /// Gets the indexes of the items that start a call group on a new line or an empty
+  /// vector when the chain shouldn't be broken up into call groups.
+  fn get_call_group_starts(items: &[MemberLikeExprItem], context: &Context) -> Vec<usize> {
+    if context.config.member_expression_chain_style != MemberChainStyle::CallGroups {
+      return Vec::new();
+    }
+
+    // keep the head along with any computed accesses (ex. `a[0]`) on the first line
+    let mut head_end = get_computed_items_end(items, 1);
+    match &items[0] {
+      // ex. `this.foo.bar`
+      MemberLikeExprItem::Node(Node::ThisExpr(_)) => {
+        while head_end < items.len() && matches!(items[head_end], MemberLikeExprItem::Node(_)) && !items[head_end].is_optional() {
+          head_end += 1;
+        }
+      }
+      // ex. `z.object()` or `Foo.create()`
+      MemberLikeExprItem::Node(Node::Ident(ident)) if is_short_or_factory_name(ident.sym(), context) => {
+        head_end = get_call_group_end(items, head_end);
+      }
+      _ => {}
+    }
+
+    let mut group_starts = Vec::new();
+    let mut index = head_end;
+    while index < items.len() {
+      group_starts.push(index);
+      index = get_call_group_end(items, index);
+    }
+
+    let call_count = items[head_end..].iter().filter(|item| matches!(item, MemberLikeExprItem::CallExpr(_))).count();
+    let has_function_args = items.iter().any(|item| match item {
+      MemberLikeExprItem::CallExpr(call_expr) => call_expr
+        .original_call_expr
+        .args
+        .iter()
+        .any(|arg| matches!(arg.expr, Expr::Arrow(_) | Expr::Fn(_))),
+      _ => false,
+    });
+    if call_count as u32 > context.config.member_expression_chain_call_threshold || has_function_args && call_count > 1 {
+      group_starts
+    } else {
+      Vec::new()
+    }
+  }
+
+  /// Gets the end of a call group, which is the property accesses up to and including
+  /// the next call along with any computed accesses after it (ex. `.a.b()[0]`).
+  fn get_call_group_end(items: &[MemberLikeExprItem], start: usize) -> usize {
+    let mut index = start;
+    while index < items.len() {
+      let is_call = matches!(items[index], MemberLikeExprItem::CallExpr(_));
+      index += 1;
+      if is_call {
+        return get_computed_items_end(items, index);
+      }
+    }
+    index
+  }
+
+  fn get_computed_items_end(items: &[MemberLikeExprItem], start: usize) -> usize {
+    let mut index = start;
+    while index < items.len() && items[index].is_computed() && !items[index].is_optional() {
+      index += 1;
+    }
+    index
+  }
+
+  fn is_short_or_factory_name(name: &str, context: &Context) -> bool {
+    name.len() <= context.config.indent_width as usize || name.chars().next().map(|c| c.is_uppercase()).unwrap_or(false)
+  }
 }
 
 struct GenComputedPropLikeOptions {
@@ -7268,6 +8118,7 @@ fn gen_decorators<'a>(decorators: &[&'a Decorator<'a>], is_inline: bool, context
       multi_line_options: ir_helpers::MultiLineOptions::same_line_no_indent(),
       force_possible_newline_at_start: false,
       node_sorter: None,
+      hugged_index: None,
     },
     context,
   );
@@ -8160,9 +9011,31 @@ fn gen_assignment_op_to<'a>(expr: Node<'a>, _op: &str, op_to: &str, context: &mu
 }
 
 fn gen_assignment_like_with_token<'a>(expr: Node<'a>, op: &str, op_token: Option<&TokenAndSpan>, context: &mut Context<'a>) -> PrintItems {
-  let use_new_line_group = get_use_new_line_group(&expr);
+  let is_auto_layout = context.config.assignment_layout == AssignmentLayout::Auto;
   let mut items = PrintItems::new();
 
+  // chained assignments (ex. `a = b = c`) share the infos of the chain's left sides
+  // so each assignment is placed on a separate line when they span multiple lines
+  let owner = expr.parent().unwrap();
+  let mut is_chain_head = false;
+  let chain_infos = if !is_auto_layout {
+    None
+  } else if let Some(infos) = context.get_info_range_for_node(&owner) {
+    Some(infos)
+  } else if matches!(expr, Node::AssignExpr(_)) {
+    let infos = (Info::new("startAssignmentChain"), Info::new("endAssignmentChain"));
+    let mut current = expr;
+    while let Node::AssignExpr(assign_expr) = current {
+      context.store_info_range_for_node(assign_expr, infos);
+      current = assign_expr.right.into();
+    }
+    is_chain_head = true;
+    items.push_info(infos.0);
+    Some(infos)
+  } else {
+    None
+  };
+
   if op == ":" {
     items.push_str(op)
   } else {
@@ -8176,19 +9049,63 @@ fn gen_assignment_like_with_token<'a>(expr: Node<'a>, op: &str, op_token: Option
   let had_op_trailing_comments = !op_trailing_comments.is_empty();
   items.extend(op_trailing_comments);
 
+  if let Some((chain_start_info, chain_end_info)) = chain_infos {
+    if !had_op_trailing_comments {
+      if matches!(expr, Node::AssignExpr(_)) {
+        // force re-evaluation of the separator once the end of the chain's left sides has been reached
+        items.push_condition(conditions::force_reevaluation_once_resolved(chain_end_info));
+        items.push_condition(conditions::new_line_if_multiple_lines_space_or_new_line_otherwise(
+          chain_start_info,
+          Some(chain_end_info),
+        ));
+        let generated_link = gen_node(expr, context);
+        items.extend(if is_chain_head {
+          conditions::indent_if_start_of_line(generated_link).into()
+        } else {
+          generated_link
+        });
+      } else {
+        // place the value of a multi-line chain on the next line
+        items.push_info(chain_end_info);
+        items.push_condition(if_true_or(
+          "newLineIfMultipleLinesAssignmentChain",
+          move |context| condition_resolvers::is_multiple_lines(context, &chain_start_info, &chain_end_info),
+          Signal::NewLine.into(),
+          Signal::SpaceOrNewLine.into(),
+        ));
+        items.push_condition(conditions::indent_if_start_of_line(gen_node(expr, context)));
+      }
+      return items;
+    }
+  }
+
+  let is_never_break_after_op = is_auto_layout && is_never_break_after_op_expr(&expr);
+  if is_never_break_after_op {
+    if let Some(call_expr) = get_member_chain_call_expr(&expr) {
+      context.store_single_line_member_chain_pos(call_expr.lo());
+    }
+  }
+  let is_break_left_side = is_auto_layout && op == "=" && is_destructuring_target_left_side(&owner);
+  // prefer breaking after the operator over breaking within the callee of other calls
+  let is_break_after_op_call = is_auto_layout && !is_never_break_after_op && is_call_like_expr(&expr);
+  let use_new_line_group = get_use_new_line_group(&expr) || is_break_left_side || is_break_after_op_call;
   let generated_assignment = {
     let mut items = PrintItems::new();
     if !had_op_trailing_comments {
-      items.push_condition(conditions::if_above_width_or(
-        context.config.indent_width,
-        {
-          let mut items = PrintItems::new();
-          items.push_signal(Signal::SpaceIfNotTrailing);
-          items.push_signal(Signal::PossibleNewLine);
-          items
-        },
-        Signal::SpaceIfNotTrailing.into(),
-      ));
+      if is_never_break_after_op || is_break_left_side {
+        items.push_signal(Signal::SpaceIfNotTrailing);
+      } else {
+        items.push_condition(conditions::if_above_width_or(
+          context.config.indent_width,
+          {
+            let mut items = PrintItems::new();
+            items.push_signal(Signal::SpaceIfNotTrailing);
+            items.push_signal(Signal::PossibleNewLine);
+            items
+          },
+          Signal::SpaceIfNotTrailing.into(),
+        ));
+      }
     }
     let assignment = gen_node(expr, context);
     let assignment = if had_op_trailing_comments {
@@ -8217,6 +9134,70 @@ fn gen_assignment_like_with_token<'a>(expr: Node<'a>, op: &str, op_token: Option
       _ => false,
     }
   }
+
+  /// Gets if the expression should be broken inside rather than after the operator.
+  fn is_never_break_after_op_expr(expr: &Node) -> bool {
+    match expr {
+      Node::CallExpr(call_expr) => match call_expr.callee {
+        Callee::Expr(callee) => is_call_that_breaks_inside(callee.into(), &call_expr.args),
+        _ => false,
+      },
+      Node::NewExpr(new_expr) => match new_expr.args.as_ref() {
+        Some(args) => is_call_that_breaks_inside(new_expr.callee.into(), args),
+        None => false,
+      },
+      Node::ObjectLit(object_lit) => !object_lit.props.is_empty(),
+      Node::ArrowExpr(_) => true,
+      Node::AwaitExpr(await_expr) => is_never_break_after_op_expr(&await_expr.arg.into()),
+      _ => false,
+    }
+  }
+
+  /// Gets the call at the end of a member chain (ex. `a.b.c(d)`), which should break inside its arguments rather than within the chain.
+  fn get_member_chain_call_expr<'a>(expr: &Node<'a>) -> Option<&'a CallExpr<'a>> {
+    match expr {
+      Node::CallExpr(call_expr) if matches!(call_expr.callee, Callee::Expr(Expr::Member(_))) => Some(call_expr),
+      Node::AwaitExpr(await_expr) => get_member_chain_call_expr(&await_expr.arg.into()),
+      _ => None,
+    }
+  }
+
+  fn is_call_like_expr(expr: &Node) -> bool {
+    match expr {
+      Node::CallExpr(_) | Node::NewExpr(_) => true,
+      Node::AwaitExpr(await_expr) => is_call_like_expr(&await_expr.arg.into()),
+      _ => false,
+    }
+  }
+
+  /// Gets if the call should break within its arguments rather than after the operator,
+  /// which is not the case for a call at the end of a member chain with arguments that can't break.
+  fn is_call_that_breaks_inside(callee: Node, args: &[&ExprOrSpread]) -> bool {
+    if args.is_empty() {
+      return false;
+    }
+    let is_short_callee = match callee {
+      Node::Ident(_) => true,
+      Node::MemberExpr(member_expr) => matches!(member_expr.obj, Expr::Ident(_) | Expr::This(_)) && matches!(member_expr.prop, MemberProp::Ident(_)),
+      _ => false,
+    };
+    let can_args_break = args.len() > 1 || matches!(args[0].expr, Expr::Object(_) | Expr::Array(_) | Expr::Arrow(_) | Expr::Fn(_));
+    is_short_callee || can_args_break
+  }
+
+  /// Gets if the left side is a destructuring pattern with multiple elements that should break before the right side.
+  fn is_destructuring_target_left_side(owner: &Node) -> bool {
+    let left_side = match owner {
+      Node::VarDeclarator(var_decl) => var_decl.name.into(),
+      Node::AssignExpr(assign_expr) => assign_expr.left.into(),
+      _ => return false,
+    };
+    match left_side {
+      Node::ObjectPat(pat) => pat.props.len() > 1,
+      Node::ArrayPat(pat) => pat.elems.len() > 1,
+      _ => false,
+    }
+  }
 }
 
 struct GenBlockOptions<'a> {
@@ -8437,9 +9418,9 @@ fn use_new_line_group_for_arrow_body(arrow_expr: &ArrowExpr, context: &Context)
     BlockStmtOrExpr::Expr(expr) => match expr {
       Expr::Paren(paren) => match paren.expr {
         Expr::Object(_) => false,
-        _ => !is_jsx_paren_expr_handled_node(&paren.expr.into(), context),
+        _ => !is_jsx_paren_expr_handled_node(&paren.expr.into(), context) && !is_multi_line_paren_expr_handled_node(&paren.expr.into(), context),
       },
-      _ => !is_jsx_paren_expr_handled_node(&expr.into(), context),
+      _ => !is_jsx_paren_expr_handled_node(&expr.into(), context) && !is_multi_line_paren_expr_handled_node(&expr.into(), context),
     },
     _ => true,
   }
@@ -0,0 +1,153 @@
+use deno_ast::swc::common::comments::Comment;
+use deno_ast::swc::common::BytePos;
+use deno_ast::swc::common::Span;
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::*;
+use dprint_core::formatting::ir_helpers::text_has_dprint_ignore;
+
+use crate::configuration::Configuration;
+
+pub struct IgnoreRangeDiagnostic {
+  pub span: Span,
+  pub message: String,
+}
+
+/// Gets the spans between each ignore start comment and its matching ignore end comment.
+///
+/// The provided comments must be sorted by position.
+pub fn get_ignore_ranges<'a>(comments: impl Iterator<Item = &'a Comment>, config: &Configuration) -> Result<Vec<Span>, IgnoreRangeDiagnostic> {
+  let mut ranges = Vec::new();
+  // the start comment along with the index of the comment text it used
+  let mut current_start: Option<(&Comment, usize)> = None;
+
+  for comment in comments {
+    if let Some(index) = find_comment_text_index(comment, &config.ignore_start_comment_text, &config.ignore_start_comment_aliases) {
+      if let Some((_, start_index)) = current_start {
+        return Err(IgnoreRangeDiagnostic {
+          span: comment.span,
+          message: format!(
+            "Expected a `{}` comment before another `{}` comment.",
+            get_end_comment_text(start_index, config),
+            get_start_comment_text(index, config),
+          ),
+        });
+      }
+      current_start = Some((comment, index));
+    } else if let Some(index) = find_comment_text_index(comment, &config.ignore_end_comment_text, &config.ignore_end_comment_aliases) {
+      match current_start.take() {
+        Some((start_comment, _)) => ranges.push(Span::new(start_comment.span.hi, comment.span.lo, Default::default())),
+        None => {
+          return Err(IgnoreRangeDiagnostic {
+            span: comment.span,
+            message: format!("Could not find a matching `{}` comment.", get_start_comment_text(index, config)),
+          })
+        }
+      }
+    }
+  }
+
+  if let Some((start_comment, index)) = current_start {
+    return Err(IgnoreRangeDiagnostic {
+      span: start_comment.span,
+      message: format!("Could not find a matching `{}` comment.", get_end_comment_text(index, config)),
+    });
+  }
+
+  Ok(ranges)
+}
+
+/// Gets a diagnostic for the first ignore range whose start and end comments are in different scopes
+/// (ex. the start comment is in a function body and the end comment is after the function).
+pub fn get_ignore_range_scope_diagnostic(ranges: &[Span], comments: &[Comment], program: &Program, config: &Configuration) -> Option<IgnoreRangeDiagnostic> {
+  for range in ranges {
+    if get_scope_span(range.lo, program) != get_scope_span(range.hi, program) {
+      let end_comment = comments.iter().find(|comment| comment.span.lo == range.hi)?;
+      let index = find_comment_text_index(end_comment, &config.ignore_end_comment_text, &config.ignore_end_comment_aliases).unwrap_or(0);
+      return Some(IgnoreRangeDiagnostic {
+        span: end_comment.span,
+        message: format!(
+          "Expected the `{}` comment to be in the same block as its `{}` comment.",
+          get_end_comment_text(index, config),
+          get_start_comment_text(index, config),
+        ),
+      });
+    }
+  }
+  None
+}
+
+/// Gets the span of the innermost node containing the position whose children may be in an ignore range.
+fn get_scope_span(pos: BytePos, program: &Program) -> Span {
+  let mut scope: Node = program.into();
+  let mut node = scope;
+  while let Some(child) = node.children().into_iter().find(|child| child.lo() < pos && pos < child.hi()) {
+    if is_ignore_range_container(&child) {
+      scope = child;
+    }
+    node = child;
+  }
+  scope.span()
+}
+
+/// Gets if the node's children may be ignored by an ignore range.
+pub fn is_ignore_range_container(node: &Node) -> bool {
+  matches!(
+    node,
+    Node::Module(_)
+      | Node::Script(_)
+      | Node::BlockStmt(_)
+      | Node::TsModuleBlock(_)
+      | Node::SwitchCase(_)
+      | Node::Class(_)
+      | Node::ObjectLit(_)
+      | Node::TsInterfaceBody(_)
+      | Node::TsTypeLit(_)
+      | Node::TsEnumDecl(_)
+      | Node::JSXElement(_)
+      | Node::JSXFragment(_)
+  )
+}
+
+/// Gets the ignore start comment text at the index, where 0 is the primary text and the rest are the aliases.
+fn get_start_comment_text(index: usize, config: &Configuration) -> &str {
+  get_comment_text_at(&config.ignore_start_comment_text, &config.ignore_start_comment_aliases, index)
+}
+
+/// Gets the ignore end comment text at the index, where 0 is the primary text and the rest are the aliases.
+fn get_end_comment_text(index: usize, config: &Configuration) -> &str {
+  get_comment_text_at(&config.ignore_end_comment_text, &config.ignore_end_comment_aliases, index)
+}
+
+fn get_comment_text_at<'a>(text: &'a str, aliases: &'a [String], index: usize) -> &'a str {
+  match index.checked_sub(1) {
+    Some(alias_index) => aliases.get(alias_index).map(|alias| alias.as_str()).unwrap_or(text),
+    None => text,
+  }
+}
+
+pub fn is_ignore_node_comment(comment: &Comment, config: &Configuration) -> bool {
+  has_any_comment_text(comment, &config.ignore_node_comment_text, &config.ignore_node_comment_aliases)
+}
+
+pub fn is_ignore_next_line_comment(comment: &Comment, config: &Configuration) -> bool {
+  has_any_comment_text(comment, &config.ignore_next_line_comment_text, &config.ignore_next_line_comment_aliases)
+}
+
+pub fn is_ignore_start_comment(comment: &Comment, config: &Configuration) -> bool {
+  has_any_comment_text(comment, &config.ignore_start_comment_text, &config.ignore_start_comment_aliases)
+}
+
+pub fn is_ignore_end_comment(comment: &Comment, config: &Configuration) -> bool {
+  has_any_comment_text(comment, &config.ignore_end_comment_text, &config.ignore_end_comment_aliases)
+}
+
+fn has_any_comment_text(comment: &Comment, text: &str, aliases: &[String]) -> bool {
+  find_comment_text_index(comment, text, aliases).is_some()
+}
+
+/// Gets the index of the text the comment has, where 0 is the primary text and the rest are the aliases.
+fn find_comment_text_index(comment: &Comment, text: &str, aliases: &[String]) -> Option<usize> {
+  std::iter::once(text)
+    .chain(aliases.iter().map(|alias| alias.as_str()))
+    .position(|text| text_has_dprint_ignore(&comment.text, text))
+}
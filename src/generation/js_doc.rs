@@ -0,0 +1,539 @@
+use deno_ast::swc::common::comments::Comment;
+use deno_ast::swc::common::comments::CommentKind;
+use deno_ast::swc::common::BytePos;
+use deno_ast::MediaType;
+use deno_ast::ParsedSource;
+use rustc_hash::FxHashMap;
+use std::path::PathBuf;
+
+use super::node_helpers;
+use crate::configuration::*;
+use crate::utils;
+
+pub fn is_js_doc(text: &str) -> bool {
+  // be strict about what a js doc is for now
+  if text.starts_with('*') && text.contains('\n') {
+    for line in text.trim().split('\n').skip(1) {
+      let first_non_whitespace = line.trim_start().chars().next();
+      if !matches!(first_non_whitespace, Some('*')) {
+        return false;
+      }
+    }
+
+    true
+  } else {
+    false
+  }
+}
+
+/// Gets if the comment text is a js doc on a single line (ex. `/** Some text */`).
+fn is_single_line_js_doc(text: &str) -> bool {
+  text.starts_with("* ") && !text.contains('\n') && !text[1..].trim().is_empty()
+}
+
+/// Gets the lines of a js doc without the leading asterisks.
+pub fn get_js_doc_lines(text: &str) -> Vec<&str> {
+  let mut lines: Vec<&str> = Vec::new();
+
+  for line in utils::split_lines(text) {
+    let line = line[get_line_start_index(line)..].trim_end();
+    if !line.is_empty() || !lines.last().map(|l| l.is_empty()).unwrap_or(false) {
+      lines.push(line);
+    }
+  }
+
+  return lines;
+
+  fn get_line_start_index(text: &str) -> usize {
+    let mut chars = text.char_indices();
+    while let Some((byte_index, c)) = chars.next() {
+      if c == '*' {
+        if matches!(chars.next(), Some((_, ' '))) {
+          return byte_index + 2;
+        } else {
+          return byte_index + 1;
+        }
+      } else if !c.is_whitespace() {
+        return byte_index;
+      }
+    }
+
+    0
+  }
+}
+
+/// Formats the js docs in a file, which, when enabled, formats the code within ts/js/tsx/jsx
+/// code fences and `@example` tags and reflows the prose to the line width.
+///
+/// This needs to be done before generating because the code is formatted by recursively
+/// formatting it as its own file. Returns the js doc lines keyed by the comment start.
+pub fn format_js_docs(parsed_source: &ParsedSource, config: &Configuration) -> FxHashMap<BytePos, Vec<String>> {
+  let is_jsx = matches!(parsed_source.media_type(), MediaType::Tsx | MediaType::Jsx | MediaType::JavaScript);
+  let mut result = FxHashMap::default();
+
+  let has_tag_formatting = config.js_doc_sort_tags || config.js_doc_align_params || config.js_doc_normalize_tag_aliases;
+  let comments = parsed_source.comments().get_vec();
+  let comment_indent_widths = get_comment_indent_widths(parsed_source, comments.iter().filter(|c| c.kind == CommentKind::Block), config);
+
+  for comment in comments.iter() {
+    if comment.kind != CommentKind::Block {
+      continue;
+    }
+    let is_single_line_js_doc = config.js_doc_collapse_single_line && is_single_line_js_doc(&comment.text);
+    if !is_js_doc(&comment.text) && !is_single_line_js_doc {
+      continue;
+    }
+
+    let lines = get_js_doc_lines(&comment.text);
+    let code_blocks = if config.js_doc_format_code {
+      get_code_blocks(&lines, is_jsx)
+    } else {
+      Vec::new()
+    };
+    if code_blocks.is_empty() && !config.js_doc_reflow && !has_tag_formatting && !config.js_doc_collapse_single_line {
+      continue;
+    }
+
+    let comment_indent_width = comment_indent_widths[&comment.span.lo];
+    let mut formatted_lines = Vec::with_capacity(lines.len());
+    let mut last_index = 0;
+    for code_block in code_blocks.iter() {
+      formatted_lines.extend(lines[last_index..code_block.start_index].iter().map(|l| l.to_string()));
+      let code_lines = &lines[code_block.start_index..code_block.end_index];
+      match format_code(code_lines, code_block, comment_indent_width, config) {
+        Some(code_lines) => formatted_lines.extend(code_lines),
+        None => formatted_lines.extend(code_lines.iter().map(|l| l.to_string())),
+      }
+      last_index = code_block.end_index;
+    }
+    formatted_lines.extend(lines[last_index..].iter().map(|l| l.to_string()));
+
+    if config.js_doc_normalize_tag_aliases {
+      normalize_tag_aliases(&mut formatted_lines);
+    }
+    if config.js_doc_sort_tags {
+      formatted_lines = sort_tags(formatted_lines);
+    }
+    if config.js_doc_reflow {
+      let max_width = config.comments_line_width.saturating_sub(comment_indent_width + " * ".len() as u32) as usize;
+      formatted_lines = reflow_lines(&formatted_lines, max_width);
+    }
+    if config.js_doc_align_params {
+      align_params(&mut formatted_lines);
+    }
+    if config.js_doc_collapse_single_line {
+      collapse_or_expand_single_line(&mut formatted_lines, comment_indent_width, config);
+    }
+
+    result.insert(comment.span.lo, formatted_lines);
+  }
+
+  result
+}
+
+struct CodeBlock<'a> {
+  /// Index of the first line of code.
+  start_index: usize,
+  /// Index after the last line of code.
+  end_index: usize,
+  indent_text: &'a str,
+  file_extension: &'static str,
+}
+
+fn get_code_blocks<'a>(lines: &[&'a str], is_jsx: bool) -> Vec<CodeBlock<'a>> {
+  let mut code_blocks = Vec::new();
+  let mut i = 0;
+
+  while i < lines.len() {
+    let line = lines[i];
+    let trimmed_line = line.trim_start();
+    if trimmed_line.starts_with("```") {
+      let end_index = match lines.iter().skip(i + 1).position(|l| l.trim() == "```") {
+        Some(index) => index + i + 1,
+        None => break, // unclosed code fence
+      };
+      if let Some(file_extension) = get_code_fence_file_extension(trimmed_line) {
+        code_blocks.push(CodeBlock {
+          start_index: i + 1,
+          end_index,
+          indent_text: &line[..line.len() - trimmed_line.len()],
+          file_extension,
+        });
+      }
+      i = end_index + 1;
+    } else if trimmed_line.starts_with("@example") {
+      let end_index = lines
+        .iter()
+        .skip(i + 1)
+        .position(|l| l.trim_start().starts_with('@'))
+        .map(|index| index + i + 1)
+        .unwrap_or(lines.len());
+      let example_lines = &lines[i + 1..end_index];
+      if example_lines.iter().any(|l| l.trim_start().starts_with("```")) {
+        // examples with code fences are handled when encountering the code fence
+        i += 1;
+      } else {
+        // leave any surrounding blank lines as-is
+        if let Some(start_offset) = example_lines.iter().position(|l| !l.is_empty()) {
+          let end_offset = example_lines.iter().rposition(|l| !l.is_empty()).unwrap() + 1;
+          code_blocks.push(CodeBlock {
+            start_index: i + 1 + start_offset,
+            end_index: i + 1 + end_offset,
+            indent_text: "",
+            file_extension: if is_jsx { "tsx" } else { "ts" },
+          });
+        }
+        i = end_index;
+      }
+    } else {
+      i += 1;
+    }
+  }
+
+  code_blocks
+}
+
+fn get_code_fence_file_extension(line: &str) -> Option<&'static str> {
+  let language = line.strip_prefix("```")?.split_whitespace().next()?;
+  match language {
+    "ts" | "typescript" => Some("ts"),
+    "tsx" => Some("tsx"),
+    "js" | "javascript" => Some("js"),
+    "jsx" => Some("jsx"),
+    _ => None,
+  }
+}
+
+/// Gets the width of the indentation each comment will have once formatted.
+///
+/// The final indentation isn't known before generating, so this uses the indent level of the
+/// enclosing nodes rather than the indentation in the file text, which would change once the
+/// file is formatted.
+pub fn get_comment_indent_widths<'a>(
+  parsed_source: &ParsedSource,
+  comments: impl Iterator<Item = &'a Comment>,
+  config: &Configuration,
+) -> FxHashMap<BytePos, u32> {
+  parsed_source.with_view(|program| {
+    comments
+      .map(|comment| {
+        (
+          comment.span.lo,
+          node_helpers::get_indent_level(comment.span.lo, &program) * config.indent_width as u32,
+        )
+      })
+      .collect()
+  })
+}
+
+fn format_code(lines: &[&str], code_block: &CodeBlock, comment_indent_width: u32, config: &Configuration) -> Option<Vec<String>> {
+  let mut code = String::new();
+  for line in lines.iter() {
+    code.push_str(if line.is_empty() { line } else { line.strip_prefix(code_block.indent_text)? });
+    code.push('\n');
+  }
+  if code.trim().is_empty() {
+    return None;
+  }
+
+  let mut config = config.clone();
+  let used_width = comment_indent_width + " * ".len() as u32 + code_block.indent_text.len() as u32;
+  config.line_width = config.line_width.saturating_sub(used_width);
+  config.file_header = None;
+
+  // leave the code as-is when it fails to parse
+  let file_path = PathBuf::from(format!("file.{}", code_block.file_extension));
+  let formatted_code = crate::format_text(&file_path, &code, &config).ok()?;
+  Some(
+    utils::split_lines(formatted_code.trim_end())
+      .map(|line| {
+        if line.is_empty() {
+          String::new()
+        } else {
+          format!("{}{}", code_block.indent_text, line)
+        }
+      })
+      .collect(),
+  )
+}
+
+/// Indentation of the lines that continue a tag description.
+const TAG_CONTINUATION_INDENT: &str = "  ";
+
+/// Re-wraps the paragraphs and tag descriptions to the provided width.
+fn reflow_lines(lines: &[String], max_width: usize) -> Vec<String> {
+  let mut result = Vec::with_capacity(lines.len());
+  let mut paragraph_lines: Vec<&str> = Vec::new();
+  let mut is_in_code_fence = false;
+  let mut is_in_example = false;
+
+  for line in lines.iter() {
+    let trimmed_line = line.trim_start();
+    if is_in_code_fence {
+      is_in_code_fence = !trimmed_line.starts_with("```");
+      result.push(line.to_string());
+    } else if trimmed_line.starts_with("```") {
+      flush_paragraph(&mut paragraph_lines, &mut result, max_width);
+      is_in_code_fence = true;
+      result.push(line.to_string());
+    } else if trimmed_line.starts_with('@') {
+      flush_paragraph(&mut paragraph_lines, &mut result, max_width);
+      is_in_example = trimmed_line.starts_with("@example");
+      if is_in_example {
+        result.push(line.to_string());
+      } else {
+        paragraph_lines.push(line);
+      }
+    } else if !is_in_example && is_tag_continuation_line(&paragraph_lines, line) {
+      paragraph_lines.push(trimmed_line);
+    } else if is_in_example || line.is_empty() || is_line_to_keep(line) {
+      flush_paragraph(&mut paragraph_lines, &mut result, max_width);
+      result.push(line.to_string());
+    } else {
+      paragraph_lines.push(line);
+    }
+  }
+  flush_paragraph(&mut paragraph_lines, &mut result, max_width);
+
+  return result;
+
+  fn flush_paragraph(paragraph_lines: &mut Vec<&str>, result: &mut Vec<String>, max_width: usize) {
+    if paragraph_lines.is_empty() {
+      return;
+    }
+
+    // the first line is on the same line as the `/**`
+    let first_line_max_width = if result.is_empty() { max_width.saturating_sub(1) } else { max_width };
+    let is_tag = paragraph_lines[0].starts_with('@');
+    let continuation_max_width = if is_tag {
+      max_width.saturating_sub(TAG_CONTINUATION_INDENT.len())
+    } else {
+      max_width
+    };
+    match utils::wrap_text(&paragraph_lines.join(" "), first_line_max_width, continuation_max_width) {
+      Some(wrapped_lines) => result.extend(wrapped_lines.into_iter().enumerate().map(|(i, line)| {
+        // indent the continuation lines of tag descriptions so they're distinguishable from the next tag
+        if is_tag && i > 0 {
+          format!("{}{}", TAG_CONTINUATION_INDENT, line)
+        } else {
+          line
+        }
+      })),
+      // leave the paragraph as-is when it has an unclosed span
+      None => result.extend(paragraph_lines.iter().map(|l| l.to_string())),
+    }
+
+    paragraph_lines.clear();
+  }
+
+  /// Gets if the line is an indented continuation of the tag description being reflowed.
+  fn is_tag_continuation_line(paragraph_lines: &[&str], line: &str) -> bool {
+    let is_in_tag = paragraph_lines.first().map(|l| l.starts_with('@')).unwrap_or(false);
+    is_in_tag && line.starts_with(char::is_whitespace) && utils::can_start_wrapped_line(line.split_whitespace().next().unwrap_or(""))
+  }
+
+  /// Gets if the line is indented or Markdown that shouldn't be joined with other lines (ex. a list item).
+  fn is_line_to_keep(line: &str) -> bool {
+    line.starts_with(char::is_whitespace) || !utils::can_start_wrapped_line(line.split_whitespace().next().unwrap_or(""))
+  }
+}
+
+/// Gets the name of the tag at the start of the line (ex. `param` for `@param {string} a`).
+fn get_tag_name(line: &str) -> Option<&str> {
+  let text = line.strip_prefix('@')?;
+  let end_index = text.find(|c: char| c.is_whitespace() || c == '{').unwrap_or(text.len());
+  if end_index == 0 {
+    None
+  } else {
+    Some(&text[..end_index])
+  }
+}
+
+/// Gets the indexes of the lines that start a tag, excluding lines within code fences.
+fn get_tag_line_indexes(lines: &[String]) -> Vec<usize> {
+  let mut indexes = Vec::new();
+  let mut is_in_code_fence = false;
+  for (i, line) in lines.iter().enumerate() {
+    if line.trim_start().starts_with("```") {
+      is_in_code_fence = !is_in_code_fence;
+    } else if !is_in_code_fence && get_tag_name(line).is_some() {
+      indexes.push(i);
+    }
+  }
+  indexes
+}
+
+fn get_canonical_tag_name(tag_name: &str) -> &str {
+  match tag_name {
+    "arg" | "argument" => "param",
+    "return" => "returns",
+    "exception" => "throws",
+    "yield" => "yields",
+    "prop" => "property",
+    "const" => "constant",
+    "augments" => "extends",
+    "virtual" => "abstract",
+    "defaultvalue" => "default",
+    "desc" => "description",
+    "fileoverview" | "overview" => "file",
+    "emits" => "fires",
+    "func" | "method" => "function",
+    "var" => "member",
+    "host" => "external",
+    _ => tag_name,
+  }
+}
+
+fn normalize_tag_aliases(lines: &mut [String]) {
+  for index in get_tag_line_indexes(lines) {
+    let line = &lines[index];
+    let tag_name = get_tag_name(line).unwrap();
+    let canonical_tag_name = get_canonical_tag_name(tag_name);
+    if canonical_tag_name != tag_name {
+      lines[index] = format!("@{}{}", canonical_tag_name, &line[1 + tag_name.len()..]);
+    }
+  }
+}
+
+/// Sorts the tags that have a known order, leaving other tags in their position.
+fn sort_tags(lines: Vec<String>) -> Vec<String> {
+  let tag_line_indexes = get_tag_line_indexes(&lines);
+  if tag_line_indexes.len() < 2 {
+    return lines;
+  }
+
+  // keep a trailing blank line at the end as that's where the comment closes
+  let mut lines = lines;
+  let trailing_blank_line = if lines.len() > 1 && lines.last().unwrap().is_empty() {
+    lines.pop()
+  } else {
+    None
+  };
+
+  let mut result = lines[..tag_line_indexes[0]].to_vec();
+  let mut tag_blocks = Vec::with_capacity(tag_line_indexes.len());
+  for (i, start_index) in tag_line_indexes.iter().enumerate() {
+    let end_index = tag_line_indexes.get(i + 1).copied().unwrap_or(lines.len());
+    tag_blocks.push(&lines[*start_index..end_index]);
+  }
+
+  let get_order = |block: &[String]| match get_canonical_tag_name(get_tag_name(&block[0]).unwrap()) {
+    "deprecated" => Some(0),
+    "param" => Some(1),
+    "returns" => Some(2),
+    "throws" => Some(3),
+    "example" => Some(4),
+    _ => None,
+  };
+  let mut sorted_blocks = tag_blocks.iter().copied().filter(|block| get_order(block).is_some()).collect::<Vec<_>>();
+  sorted_blocks.sort_by_key(|block| get_order(block)); // stable
+  let mut sorted_blocks = sorted_blocks.into_iter();
+  for block in tag_blocks.iter() {
+    let block = if get_order(block).is_some() { sorted_blocks.next().unwrap() } else { block };
+    result.extend(block.iter().cloned());
+  }
+
+  result.extend(trailing_blank_line);
+  result
+}
+
+/// Aligns the types, names, and descriptions of consecutive `@param` tags.
+fn align_params(lines: &mut [String]) {
+  let tag_line_indexes = get_tag_line_indexes(lines);
+  let mut group: Vec<(usize, ParamTag)> = Vec::new();
+
+  for (i, index) in tag_line_indexes.iter().enumerate() {
+    let is_consecutive = i > 0 && tag_line_indexes[i - 1] + 1 == *index;
+    if !is_consecutive {
+      align_group(lines, std::mem::take(&mut group));
+    }
+    match parse_param_tag(&lines[*index]) {
+      Some(param_tag) => group.push((*index, param_tag)),
+      None => align_group(lines, std::mem::take(&mut group)),
+    }
+  }
+  align_group(lines, group);
+
+  fn align_group(lines: &mut [String], group: Vec<(usize, ParamTag)>) {
+    if group.len() < 2 {
+      return;
+    }
+
+    let head_width = group.iter().map(|(_, tag)| tag.head.chars().count()).max().unwrap();
+    let name_width = group.iter().map(|(_, tag)| tag.name.chars().count()).max().unwrap();
+    for (index, tag) in group {
+      let mut line = format!("{:<head_width$} {:<name_width$} {}", tag.head, tag.name, tag.description);
+      line.truncate(line.trim_end().len());
+      lines[index] = line;
+    }
+  }
+}
+
+struct ParamTag {
+  /// The tag name and type (ex. `@param {string}`).
+  head: String,
+  name: String,
+  description: String,
+}
+
+fn parse_param_tag(line: &str) -> Option<ParamTag> {
+  if get_tag_name(line) != Some("param") {
+    return None;
+  }
+
+  let mut remaining = line["@param".len()..].trim_start();
+  let mut head = String::from("@param");
+  if remaining.starts_with('{') {
+    let end_index = get_balanced_end_index(remaining, '{', '}')?;
+    head.push(' ');
+    head.push_str(&remaining[..end_index]);
+    remaining = remaining[end_index..].trim_start();
+  }
+
+  // optional parameters may contain spaces (ex. `[name = "default"]`)
+  let name_end_index = if remaining.starts_with('[') {
+    get_balanced_end_index(remaining, '[', ']')?
+  } else {
+    remaining.find(char::is_whitespace).unwrap_or(remaining.len())
+  };
+  if name_end_index == 0 {
+    return None;
+  }
+
+  Some(ParamTag {
+    head,
+    name: remaining[..name_end_index].to_string(),
+    description: remaining[name_end_index..].trim_start().to_string(),
+  })
+}
+
+fn get_balanced_end_index(text: &str, open_char: char, close_char: char) -> Option<usize> {
+  let mut depth = 0;
+  for (byte_index, c) in text.char_indices() {
+    if c == open_char {
+      depth += 1;
+    } else if c == close_char {
+      depth -= 1;
+      if depth == 0 {
+        return Some(byte_index + c.len_utf8());
+      }
+    }
+  }
+  None
+}
+
+/// Collapses a js doc with a single line of text to `/** text */` when it fits within
+/// the comments line width or expands a single line js doc to multiple lines when it doesn't.
+fn collapse_or_expand_single_line(lines: &mut Vec<String>, comment_indent_width: u32, config: &Configuration) {
+  let mut text_lines = lines.iter().filter(|line| !line.is_empty());
+  let text = match (text_lines.next(), text_lines.next()) {
+    (Some(text), None) => text.clone(),
+    _ => return,
+  };
+  let single_line_width = comment_indent_width as usize + "/** ".len() + text.chars().count() + " */".len();
+  if single_line_width <= config.comments_line_width as usize {
+    *lines = vec![text];
+  } else if lines.len() == 1 {
+    *lines = vec![String::new(), text, String::new()];
+  }
+}
@@ -0,0 +1,217 @@
+use deno_ast::swc::common::comments::Comment;
+use deno_ast::swc::common::comments::CommentKind;
+use deno_ast::swc::common::BytePos;
+use deno_ast::ParsedSource;
+use rustc_hash::FxHashMap;
+
+use super::*;
+use crate::configuration::*;
+use crate::utils;
+
+pub struct WrappedLineComments {
+  /// The text of each line after the slashes.
+  pub lines: Vec<String>,
+  /// The start positions of the comments after the first one that are now a part of these lines.
+  pub merged_comment_starts: Vec<BytePos>,
+}
+
+/// Wraps the paragraphs of consecutive line comments that exceed the comment line width.
+///
+/// Returns the wrapped lines keyed by the start of the first comment in the group.
+pub fn wrap_line_comments(parsed_source: &ParsedSource, config: &Configuration) -> FxHashMap<BytePos, WrappedLineComments> {
+  let mut result = FxHashMap::default();
+  if !config.comment_line_wrap {
+    return result;
+  }
+
+  let file_text = parsed_source.source().text_str();
+  let mut comments = parsed_source.comments().get_vec();
+  comments.sort_by_key(|comment| comment.span.lo);
+
+  let groups = get_comment_groups(file_text, &comments);
+  let indent_widths = get_comment_indent_widths(parsed_source, groups.iter().map(|group| group[0]), config);
+  for group in groups {
+    let indent_width = indent_widths[&group[0].span.lo];
+    let max_width = config.comments_line_width.saturating_sub(indent_width + "// ".len() as u32) as usize;
+    if let Some(lines) = wrap_lines(&group, max_width, config) {
+      result.insert(
+        group[0].span.lo,
+        WrappedLineComments {
+          lines,
+          merged_comment_starts: group[1..].iter().map(|comment| comment.span.lo).collect(),
+        },
+      );
+    }
+  }
+
+  result
+}
+
+/// Gets the runs of line comments at the end of consecutive lines that have the same indentation.
+///
+/// Returns the start and width of each comment in the runs that have more than one comment.
+pub fn get_trailing_line_comment_runs(parsed_source: &ParsedSource, config: &Configuration) -> Vec<Vec<(BytePos, u32)>> {
+  let mut runs: Vec<Vec<(BytePos, u32)>> = Vec::new();
+  if !config.align_trailing_comments {
+    return runs;
+  }
+
+  let text_info = parsed_source.source();
+  let file_text = text_info.text_str();
+  let mut comments = parsed_source.comments().get_vec();
+  comments.sort_by_key(|comment| comment.span.lo);
+
+  let mut current_run = Vec::new();
+  let mut last_line_index_and_indent = None;
+  for comment in comments.iter() {
+    if comment.kind != CommentKind::Line || get_indent_text(file_text, comment).is_some() {
+      continue;
+    }
+
+    let line_index = text_info.line_index(comment.span.lo);
+    let line_text = &file_text[text_info.line_start(line_index).0 as usize..];
+    let indent_text = &line_text[..line_text.len() - line_text.trim_start().len()];
+    let continues_run = match last_line_index_and_indent {
+      Some((last_line_index, last_indent_text)) => last_line_index + 1 == line_index && last_indent_text == indent_text,
+      None => false,
+    };
+    if !continues_run && current_run.len() > 1 {
+      runs.push(std::mem::take(&mut current_run));
+    } else if !continues_run {
+      current_run.clear();
+    }
+    let width = "//".len() + comment.text.trim_end().chars().count();
+    current_run.push((comment.span.lo, width as u32));
+    last_line_index_and_indent = Some((line_index, indent_text));
+  }
+  if current_run.len() > 1 {
+    runs.push(current_run);
+  }
+
+  runs
+}
+
+/// Gets the groups of line comments that are each on their own line at the same indentation.
+fn get_comment_groups<'a>(file_text: &str, comments: &'a [Comment]) -> Vec<Vec<&'a Comment>> {
+  let mut groups: Vec<Vec<&'a Comment>> = Vec::new();
+  let mut last_indent_text = None;
+
+  for comment in comments.iter() {
+    let indent_text = if comment.kind == CommentKind::Line {
+      get_indent_text(file_text, comment)
+    } else {
+      None
+    };
+    let indent_text = match indent_text {
+      Some(indent_text) => indent_text,
+      None => {
+        last_indent_text = None;
+        continue;
+      }
+    };
+
+    let continues_group = match (groups.last().and_then(|group| group.last()), last_indent_text) {
+      (Some(last_comment), Some(last_indent_text)) => {
+        let text_between = &file_text[last_comment.span.hi.0 as usize..comment.span.lo.0 as usize];
+        last_indent_text == indent_text && text_between.trim().is_empty() && text_between.matches('\n').count() == 1
+      }
+      _ => false,
+    };
+    if continues_group {
+      groups.last_mut().unwrap().push(comment);
+    } else {
+      groups.push(vec![comment]);
+    }
+    last_indent_text = Some(indent_text);
+  }
+
+  groups
+}
+
+/// Gets the text before the comment on its line when it only consists of whitespace.
+fn get_indent_text<'a>(file_text: &'a str, comment: &Comment) -> Option<&'a str> {
+  let text_before = &file_text[..comment.span.lo.0 as usize];
+  let line_start = text_before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+  let indent_text = &text_before[line_start..];
+  if indent_text.trim().is_empty() {
+    Some(indent_text)
+  } else {
+    None
+  }
+}
+
+/// Wraps the paragraphs in the group, returning `None` when nothing changed.
+fn wrap_lines(comments: &[&Comment], max_width: usize, config: &Configuration) -> Option<Vec<String>> {
+  let mut result = Vec::with_capacity(comments.len());
+  let mut paragraph_lines: Vec<&str> = Vec::new();
+  let mut has_changed = false;
+
+  for comment in comments.iter() {
+    if is_line_to_keep(comment, config) {
+      has_changed |= flush_paragraph(&mut paragraph_lines, &mut result, max_width);
+      result.push(comment.text.to_string());
+    } else {
+      paragraph_lines.push(comment.text.trim());
+    }
+  }
+  has_changed |= flush_paragraph(&mut paragraph_lines, &mut result, max_width);
+
+  if has_changed {
+    Some(result)
+  } else {
+    None
+  }
+}
+
+fn flush_paragraph(paragraph_lines: &mut Vec<&str>, result: &mut Vec<String>, max_width: usize) -> bool {
+  let lines = std::mem::take(paragraph_lines);
+  let exceeds_width = lines.iter().any(|line| line.chars().count() > max_width);
+  let wrapped_lines = if exceeds_width {
+    utils::wrap_text(&lines.join(" "), max_width, max_width)
+  } else {
+    None
+  };
+
+  match wrapped_lines {
+    Some(wrapped_lines) => {
+      let has_changed = wrapped_lines.iter().map(|l| l.as_str()).ne(lines.iter().copied());
+      result.extend(wrapped_lines.into_iter().map(|line| format!(" {}", line)));
+      has_changed
+    }
+    None => {
+      result.extend(lines.into_iter().map(|line| format!(" {}", line)));
+      false
+    }
+  }
+}
+
+/// Gets if the comment line should be left as-is and not be a part of a paragraph.
+fn is_line_to_keep(comment: &Comment, config: &Configuration) -> bool {
+  let text = &comment.text;
+  let trimmed_text = text.trim();
+  // only wrap lines with a single space after the slashes (this excludes `///` directives and indented text)
+  if !text.starts_with(' ') || text[1..].starts_with(char::is_whitespace) || trimmed_text.is_empty() {
+    return true;
+  }
+  if !utils::can_start_wrapped_line(trimmed_text.split_whitespace().next().unwrap()) {
+    return true;
+  }
+
+  is_code_like(trimmed_text) || is_directive(trimmed_text) || trimmed_text.contains("://") || is_ignore_comment(comment, config)
+}
+
+fn is_code_like(text: &str) -> bool {
+  text.ends_with([';', '{', '}', '(', '[']) || text.contains("=>") || text.contains(" = ")
+}
+
+fn is_directive(text: &str) -> bool {
+  const DIRECTIVE_PREFIXES: [&str; 7] = ["eslint", "prettier-ignore", "istanbul ", "c8 ", "tslint:", "jshint", "global "];
+  DIRECTIVE_PREFIXES.iter().any(|prefix| text.starts_with(prefix))
+}
+
+fn is_ignore_comment(comment: &Comment, config: &Configuration) -> bool {
+  is_ignore_node_comment(comment, config)
+    || is_ignore_next_line_comment(comment, config)
+    || is_ignore_start_comment(comment, config)
+    || is_ignore_end_comment(comment, config)
+}
@@ -1,10 +1,17 @@
 mod comments;
 mod context;
+mod directive_comments;
 mod generate;
 mod generate_types;
 mod helpers;
+mod ignore_comments;
+mod js_doc;
+mod line_comments;
 mod node_helpers;
+mod numeric_literals;
+mod redundant_parens;
 mod sorting;
+mod string_literals;
 mod swc;
 mod tokens;
 
@@ -12,6 +19,17 @@ use comments::*;
 use context::*;
 use generate_types::*;
 use helpers::*;
+use ignore_comments::*;
+use js_doc::*;
+use line_comments::*;
+use numeric_literals::*;
+use redundant_parens::*;
+use string_literals::*;
 use tokens::*;
 
+pub use context::FormatWithHost;
+pub use directive_comments::file_text_has_directive_comment;
+pub use directive_comments::get_moved_directive_comments;
 pub use generate::generate;
+pub use ignore_comments::get_ignore_range_scope_diagnostic;
+pub use ignore_comments::get_ignore_ranges;
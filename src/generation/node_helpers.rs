@@ -18,6 +18,45 @@ pub fn is_first_node_on_line(node: &dyn Spanned, program: &Program) -> bool {
   true
 }
 
+/// Gets the whitespace at the start of the line the position is on.
+pub fn get_line_indent_text<'a>(pos: BytePos, program: &Program<'a>) -> &'a str {
+  let source_file_text = program.source_file().unwrap().text();
+  let line_start = source_file_text[..pos.0 as usize].rfind('\n').map(|index| index + 1).unwrap_or(0);
+  let line_text = &source_file_text[line_start..];
+  &line_text[..line_text.len() - line_text.trim_start_matches([' ', '\t']).len()]
+}
+
+/// Gets the number of nodes the position is within that indent their contents once formatted
+/// (ex. blocks, classes, and object literals).
+pub fn get_indent_level(pos: BytePos, program: &Program) -> u32 {
+  let mut indent_level = 0;
+  let mut node: Node = program.into();
+  while let Some(child) = node.children().into_iter().find(|child| child.lo() < pos && pos < child.hi()) {
+    if is_indenting_container(&child, pos) {
+      indent_level += 1;
+    }
+    node = child;
+  }
+  return indent_level;
+
+  fn is_indenting_container(node: &Node, pos: BytePos) -> bool {
+    match node {
+      Node::BlockStmt(_)
+      | Node::Class(_)
+      | Node::ObjectLit(_)
+      | Node::ObjectPat(_)
+      | Node::ArrayLit(_)
+      | Node::TsInterfaceBody(_)
+      | Node::TsTypeLit(_)
+      | Node::TsEnumDecl(_)
+      | Node::TsModuleBlock(_)
+      | Node::SwitchCase(_) => true,
+      Node::SwitchStmt(switch_stmt) => pos > switch_stmt.discriminant.hi(),
+      _ => false,
+    }
+  }
+}
+
 pub fn has_separating_blank_line(first_node: &dyn Spanned, second_node: &dyn Spanned, program: &Program) -> bool {
   return get_second_start_line(first_node, second_node, program) > first_node.end_line_fast(program) + 1;
 
@@ -0,0 +1,132 @@
+use crate::configuration::*;
+
+/// Gets the text of a numeric or big int literal normalized based on the configuration.
+///
+/// This only changes the way the literal is written and never its value.
+pub fn get_numeric_literal_text(text: &str, config: &Configuration) -> String {
+  let (text, big_int_suffix) = match text.strip_suffix('n') {
+    Some(text) => (text, "n"),
+    None => (text, ""),
+  };
+  let mut chars = text.chars();
+  let first_char = chars.next();
+  let second_char = chars.next();
+
+  match (first_char, second_char) {
+    (Some('0'), Some(prefix_char @ ('x' | 'X' | 'o' | 'O' | 'b' | 'B'))) => {
+      let prefix_char = config.numeric_literal_prefix_case.apply(&prefix_char.to_string());
+      let digits = if prefix_char.eq_ignore_ascii_case("x") {
+        config.numeric_literal_digit_case.apply(&text[2..])
+      } else {
+        text[2..].to_string()
+      };
+      format!("0{}{}{}", prefix_char, digits, big_int_suffix)
+    }
+    // legacy octal literals (ex. `0777`) and decimals with leading zeros (ex. `09.5`) are kept as-is
+    (Some('0'), Some('0'..='9' | '_')) => format!("{}{}", text, big_int_suffix),
+    _ => format!("{}{}", get_decimal_text(text, config), big_int_suffix),
+  }
+}
+
+fn get_decimal_text(text: &str, config: &Configuration) -> String {
+  let (mantissa, exponent) = match text.find(['e', 'E']) {
+    Some(index) => (&text[..index], Some((&text[index..index + 1], &text[index + 1..]))),
+    None => (text, None),
+  };
+  let (mut integer, mut fraction) = match mantissa.find('.') {
+    Some(index) => (&mantissa[..index], Some(&mantissa[index + 1..])),
+    None => (mantissa, None),
+  };
+
+  if config.numeric_literal_remove_trailing_zeros {
+    fraction = fraction
+      .map(|fraction| fraction.trim_end_matches(['0', '_']))
+      .filter(|fraction| !fraction.is_empty());
+  }
+  if integer.is_empty() && (config.numeric_literal_add_leading_zero || fraction.is_none()) {
+    integer = "0";
+  }
+
+  let mut result = integer.to_string();
+  if let Some(fraction) = fraction {
+    result.push('.');
+    result.push_str(fraction);
+  }
+  if let Some((exponent_char, exponent_digits)) = exponent {
+    result.push_str(&config.numeric_literal_exponent_case.apply(exponent_char));
+    result.push_str(exponent_digits);
+  }
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::configuration::ConfigurationBuilder;
+
+  fn get_config() -> Configuration {
+    ConfigurationBuilder::new()
+      .numeric_literal_prefix_case(LetterCase::Lowercase)
+      .numeric_literal_digit_case(LetterCase::Uppercase)
+      .numeric_literal_exponent_case(LetterCase::Lowercase)
+      .numeric_literal_add_leading_zero(true)
+      .numeric_literal_remove_trailing_zeros(true)
+      .build()
+  }
+
+  fn run_test(text: &str, expected: &str) {
+    let result = get_numeric_literal_text(text, &get_config());
+    assert_eq!(result, expected);
+    assert_eq!(get_value(&result), get_value(text), "value changed for {}", text);
+  }
+
+  fn get_value(text: &str) -> f64 {
+    let text = text.trim_end_matches('n').replace('_', "");
+    let lower_text = text.to_ascii_lowercase();
+    let radix = match lower_text.get(..2) {
+      Some("0x") => 16,
+      Some("0o") => 8,
+      Some("0b") => 2,
+      _ => return text.parse::<f64>().unwrap(),
+    };
+    u128::from_str_radix(&text[2..], radix).unwrap() as f64
+  }
+
+  #[test]
+  fn it_should_normalize_prefixes_and_digits() {
+    run_test("0XaBc", "0xABC");
+    run_test("0O17", "0o17");
+    run_test("0B1010_0101", "0b1010_0101");
+    run_test("0XFF_ffn", "0xFF_FFn");
+  }
+
+  #[test]
+  fn it_should_normalize_exponents() {
+    run_test("1E5", "1e5");
+    run_test("1.50E-5", "1.5e-5");
+    run_test("5.E+3", "5e+3");
+  }
+
+  #[test]
+  fn it_should_normalize_leading_and_trailing_zeros() {
+    run_test(".5", "0.5");
+    run_test("5.", "5");
+    run_test("5.0", "5");
+    run_test("1.500", "1.5");
+    run_test("1_000.000_0", "1_000");
+    run_test(".0", "0");
+    run_test("0.0", "0");
+  }
+
+  #[test]
+  fn it_should_keep_legacy_octal_literals() {
+    run_test("0777", "0777");
+    run_test("09.50", "09.50");
+  }
+
+  #[test]
+  fn it_should_keep_big_int_suffix() {
+    run_test("10n", "10n");
+    run_test("0b11n", "0b11n");
+  }
+}
@@ -0,0 +1,236 @@
+use deno_ast::swc::common::Spanned;
+use deno_ast::view::*;
+
+/// Gets if removing the parentheses of the paren expression wouldn't change the meaning of the code.
+pub fn is_redundant_paren_expr(node: &ParenExpr) -> bool {
+  let expr = node.expr.as_node();
+  let precedence = get_expr_precedence(&expr);
+  if precedence == 0 || matches!(expr, Node::JSXElement(_) | Node::JSXFragment(_)) {
+    return false;
+  }
+
+  let node_lo = node.lo();
+  let is_redundant = match node.parent() {
+    Node::IfStmt(_) | Node::WhileStmt(_) | Node::DoWhileStmt(_) => !matches!(expr, Node::AssignExpr(_)),
+    Node::ForStmt(parent) => !matches!(expr, Node::AssignExpr(_)) || parent.init.map(|init| init.lo() == node_lo).unwrap_or(false),
+    Node::ReturnStmt(_)
+    | Node::ThrowStmt(_)
+    | Node::VarDeclarator(_)
+    | Node::SwitchStmt(_)
+    | Node::SwitchCase(_)
+    | Node::KeyValueProp(_)
+    | Node::AssignPat(_)
+    | Node::ClassProp(_)
+    | Node::PrivateProp(_)
+    | Node::SpreadElement(_)
+    | Node::Tpl(_)
+    | Node::YieldExpr(_)
+    | Node::TsEnumMember(_)
+    | Node::ArrowExpr(_)
+    | Node::ExportDefaultExpr(_)
+    | Node::TsExportAssignment(_) => true,
+    // ex. `for ((async) of x)` and `for ((let) of x)` would change on the left side
+    Node::ForInStmt(parent) => parent.right.lo() == node_lo,
+    Node::ForOfStmt(parent) => parent.right.lo() == node_lo,
+    Node::CondExpr(parent) => parent.test.lo() != node_lo || precedence > 2,
+    Node::BinExpr(parent) => match expr {
+      Node::BinExpr(child) => is_redundant_in_bin_expr(parent, child, parent.left.lo() == node_lo),
+      // ex. `(-a) ** 2` is a syntax error
+      _ => precedence > 14 || precedence == 14 && !(parent.op() == BinaryOp::Exp && parent.left.lo() == node_lo),
+    },
+    Node::UnaryExpr(parent) => precedence >= 14 && !is_unary_sign_merge(parent.op(), &expr),
+    Node::AwaitExpr(_) => precedence >= 14,
+    Node::MemberExpr(_)
+    | Node::CallExpr(_)
+    | Node::TaggedTpl(_)
+    | Node::TsNonNullExpr(_)
+    | Node::TsAsExpr(_)
+    | Node::TsConstAssertion(_)
+    | Node::TsTypeAssertion(_) => {
+      // ex. `(1).toString()` and `(a?.b).c` would change
+      precedence >= 16 && !matches!(expr, Node::Number(_) | Node::OptChainExpr(_))
+    }
+    Node::NewExpr(_) => is_new_callee_without_calls(&expr),
+    _ => false,
+  };
+
+  is_redundant && !starts_statement_with_ambiguous_node(node, &expr) && !is_in_for_init_with_in_operator(node, &expr)
+}
+
+/// Gets if removing the parentheses of the parenthesized type wouldn't change the meaning of the code.
+pub fn is_redundant_paren_type(node: &TsParenthesizedType) -> bool {
+  let precedence = get_type_precedence(&node.type_ann.into());
+  let node_lo = node.lo();
+  match node.parent() {
+    Node::TsArrayType(_) => precedence >= 4,
+    Node::TsIndexedAccessType(parent) => parent.obj_type.lo() != node_lo || precedence >= 4,
+    Node::TsTypeOperator(_) => precedence >= 3,
+    Node::TsUnionType(_) => precedence >= 1,
+    Node::TsIntersectionType(_) => precedence >= 2,
+    Node::TsConditionalType(parent) => precedence >= 1 || parent.true_type.lo() == node_lo || parent.false_type.lo() == node_lo,
+    // ex. the return type of an arrow function with a function type
+    Node::TsTypeAnn(parent) => precedence >= 1 || !matches!(parent.parent(), Node::ArrowExpr(_)),
+    Node::TsTypeAliasDecl(_) | Node::TsTypeParamInstantiation(_) | Node::TsTupleElement(_) | Node::TsParenthesizedType(_) | Node::TsTypeParam(_) => true,
+    _ => false,
+  }
+}
+
+/// Gets the precedence of the expression where 0 is one that should always keep its parentheses.
+fn get_expr_precedence(node: &Node) -> u8 {
+  match node {
+    Node::AssignExpr(_) | Node::ArrowExpr(_) | Node::YieldExpr(_) | Node::TsAsExpr(_) | Node::TsConstAssertion(_) => 1,
+    Node::CondExpr(_) => 2,
+    Node::BinExpr(node) => 2 + node.op().precedence(),
+    Node::UnaryExpr(_) | Node::AwaitExpr(_) | Node::TsTypeAssertion(_) => 14,
+    Node::UpdateExpr(node) => {
+      if node.prefix() {
+        14
+      } else {
+        15
+      }
+    }
+    // new expressions are always generated with arguments
+    Node::CallExpr(_)
+    | Node::NewExpr(_)
+    | Node::MemberExpr(_)
+    | Node::OptChainExpr(_)
+    | Node::TaggedTpl(_)
+    | Node::TsNonNullExpr(_)
+    | Node::SuperPropExpr(_)
+    | Node::MetaPropExpr(_) => 16,
+    Node::Ident(_)
+    | Node::ThisExpr(_)
+    | Node::Str(_)
+    | Node::Number(_)
+    | Node::BigInt(_)
+    | Node::Bool(_)
+    | Node::Null(_)
+    | Node::Regex(_)
+    | Node::Tpl(_)
+    | Node::ArrayLit(_)
+    | Node::ObjectLit(_)
+    | Node::FnExpr(_)
+    | Node::ClassExpr(_)
+    | Node::ParenExpr(_) => 17,
+    _ => 0,
+  }
+}
+
+/// Gets the precedence of the type where 0 is one that should always keep its parentheses.
+fn get_type_precedence(node: &Node) -> u8 {
+  match node {
+    Node::TsUnionType(_) => 1,
+    Node::TsIntersectionType(_) => 2,
+    Node::TsTypeOperator(_) | Node::TsTypeQuery(_) => 3,
+    Node::TsArrayType(_) | Node::TsIndexedAccessType(_) => 4,
+    Node::TsKeywordType(_)
+    | Node::TsThisType(_)
+    | Node::TsTypeRef(_)
+    | Node::TsTypeLit(_)
+    | Node::TsTupleType(_)
+    | Node::TsLitType(_)
+    | Node::TsMappedType(_)
+    | Node::TsParenthesizedType(_) => 5,
+    _ => 0,
+  }
+}
+
+fn is_redundant_in_bin_expr(parent: &BinExpr, child: &BinExpr, is_left: bool) -> bool {
+  let parent_op = parent.op();
+  let child_op = child.op();
+  // mixing `??` with `||` or `&&` is a syntax error
+  let is_nullish_mix = |a: BinaryOp, b: BinaryOp| a == BinaryOp::NullishCoalescing && matches!(b, BinaryOp::LogicalOr | BinaryOp::LogicalAnd);
+  if is_nullish_mix(parent_op, child_op) || is_nullish_mix(child_op, parent_op) {
+    return false;
+  }
+
+  let parent_precedence = parent_op.precedence();
+  let child_precedence = child_op.precedence();
+  if child_precedence != parent_precedence {
+    child_precedence > parent_precedence
+  } else if parent_op == BinaryOp::Exp {
+    !is_left
+  } else {
+    is_left
+  }
+}
+
+/// Gets if removing the parentheses would merge the signs (ex. `-(-a)` to `--a`).
+fn is_unary_sign_merge(op: UnaryOp, expr: &Node) -> bool {
+  let sign = match op {
+    UnaryOp::Minus => UnaryOp::Minus,
+    UnaryOp::Plus => UnaryOp::Plus,
+    _ => return false,
+  };
+  match expr {
+    Node::UnaryExpr(expr) => expr.op() == sign,
+    Node::UpdateExpr(expr) => expr.prefix() && (expr.op() == UpdateOp::MinusMinus) == (sign == UnaryOp::Minus),
+    _ => false,
+  }
+}
+
+/// Gets if the expression can be the callee of a new expression without parentheses (ex. `new (a.b())()` can't).
+fn is_new_callee_without_calls(expr: &Node) -> bool {
+  match expr {
+    Node::Ident(_) | Node::ThisExpr(_) => true,
+    Node::MemberExpr(member_expr) => is_new_callee_without_calls(&member_expr.obj.into()),
+    _ => false,
+  }
+}
+
+/// Gets if the parenthesized expression starts a statement, arrow function body, or default export
+/// and removing the parentheses would make it start with `{`, `function`, `class`, or `let`.
+fn starts_statement_with_ambiguous_node(node: &ParenExpr, expr: &Node) -> bool {
+  let mut current: Node = node.into();
+  loop {
+    let parent = match current.parent() {
+      Some(parent) => parent,
+      None => return false,
+    };
+    let is_start = match parent {
+      Node::ExprStmt(_) | Node::ExportDefaultExpr(_) => true,
+      Node::ArrowExpr(arrow_expr) => arrow_expr.body.lo() == current.lo(),
+      _ => false,
+    };
+    if is_start {
+      break;
+    }
+    if parent.lo() != current.lo() {
+      return false;
+    }
+    current = parent;
+  }
+
+  let mut current = *expr;
+  loop {
+    match current {
+      Node::ObjectLit(_) | Node::FnExpr(_) | Node::ClassExpr(_) => return true,
+      Node::Ident(ident) if ident.sym() == "let" => return true,
+      Node::ParenExpr(_) => return false,
+      _ => {}
+    }
+    match current.children().into_iter().find(|child| child.lo() == current.lo()) {
+      Some(child) => current = child,
+      None => return false,
+    }
+  }
+}
+
+/// Gets if the expression is in the initializer of a for statement and has an `in` operator,
+/// which needs to be in parentheses (ex. `for (let i = ("a" in b); ;) {}`).
+fn is_in_for_init_with_in_operator(node: &ParenExpr, expr: &Node) -> bool {
+  let is_in_for_init = node.ancestors().any(|ancestor| match ancestor {
+    Node::ForStmt(for_stmt) => for_stmt.init.map(|init| init.span().contains(node.span())).unwrap_or(false),
+    _ => false,
+  });
+  is_in_for_init && has_in_operator(expr)
+}
+
+fn has_in_operator(node: &Node) -> bool {
+  if let Node::BinExpr(bin_expr) = node {
+    if bin_expr.op() == BinaryOp::In {
+      return true;
+    }
+  }
+  node.children().iter().any(has_in_operator)
+}
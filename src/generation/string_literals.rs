@@ -0,0 +1,184 @@
+use crate::configuration::*;
+
+/// Gets the text between the quotes of a string literal with its escapes normalized based on the configuration.
+///
+/// This only changes the way the string is written and never its value.
+pub fn get_string_text_with_normalized_escapes(text: &str, config: &Configuration) -> String {
+  let mut result = String::with_capacity(text.len());
+  let mut remaining_text = text;
+
+  while let Some(index) = remaining_text.find('\\') {
+    result.push_str(&remaining_text[..index]);
+    let escape_text = &remaining_text[index + 1..];
+    let escaped_char = match escape_text.chars().next() {
+      Some(escaped_char) => escaped_char,
+      None => {
+        remaining_text = escape_text;
+        result.push('\\');
+        break;
+      }
+    };
+    let text_after_char = &escape_text[escaped_char.len_utf8()..];
+    remaining_text = text_after_char;
+
+    match escaped_char {
+      'x' | 'u' => match parse_code_escape(escaped_char, text_after_char) {
+        Some((code_point, hex_text, len)) => {
+          let (value_char, value_len) = match get_surrogate_pair_char(code_point, &text_after_char[len..]) {
+            Some((value_char, low_len)) => (Some(value_char), len + low_len),
+            None => (char::from_u32(code_point), len),
+          };
+          match value_char {
+            Some(value_char) if config.string_literal_unescape_printable && is_printable_char(value_char) => {
+              result.push(value_char);
+              remaining_text = &text_after_char[value_len..];
+            }
+            _ => {
+              result.push('\\');
+              result.push(escaped_char);
+              result.push_str(&config.string_literal_unicode_escape_case.apply(hex_text));
+              remaining_text = &text_after_char[len..];
+            }
+          }
+        }
+        None => {
+          result.push('\\');
+          result.push(escaped_char);
+        }
+      },
+      'b' | 'f' | 'n' | 'r' | 't' | 'v' | '0'..='9' | '\\' | '\'' | '"' | '\r' | '\n' | '\u{2028}' | '\u{2029}' => {
+        result.push('\\');
+        result.push(escaped_char);
+      }
+      _ => {
+        if !config.string_literal_remove_unnecessary_escapes {
+          result.push('\\');
+        }
+        result.push(escaped_char);
+      }
+    }
+  }
+
+  result.push_str(remaining_text);
+  result
+}
+
+/// Gets the character of a high surrogate code point followed by a low surrogate
+/// escape (ex. `\uD83D\uDE00`) along with the length of the low surrogate escape.
+fn get_surrogate_pair_char(code_point: u32, text_after: &str) -> Option<(char, usize)> {
+  if !(0xD800..=0xDBFF).contains(&code_point) {
+    return None;
+  }
+  let (low_code_point, _, len) = text_after.strip_prefix("\\u").and_then(|text| parse_code_escape('u', text))?;
+  if !(0xDC00..=0xDFFF).contains(&low_code_point) {
+    return None;
+  }
+  let value_char = char::from_u32(0x10000 + ((code_point - 0xD800) << 10) + (low_code_point - 0xDC00))?;
+  Some((value_char, "\\u".len() + len))
+}
+
+/// Parses the text after `\x` or `\u` returning the code point, its hex digits text, and the length of the text.
+fn parse_code_escape(kind: char, text: &str) -> Option<(u32, &str, usize)> {
+  let (hex_text, len) = if kind == 'u' && text.starts_with('{') {
+    let end = text.find('}')?;
+    (&text[..end + 1], end + 1)
+  } else {
+    let len = if kind == 'x' { 2 } else { 4 };
+    (text.get(..len)?, len)
+  };
+  let digits = hex_text.trim_start_matches('{').trim_end_matches('}');
+  if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+    return None;
+  }
+  u32::from_str_radix(digits, 16).ok().map(|code_point| (code_point, hex_text, len))
+}
+
+/// Gets if the character is visible when written literally and so doesn't need to be escaped.
+fn is_printable_char(c: char) -> bool {
+  if c == ' ' {
+    return true;
+  }
+  !(c == '\\'
+    || c.is_control()
+    || c.is_whitespace()
+    || matches!(
+      c,
+      '\u{00AD}'
+        | '\u{0300}'..='\u{036F}'
+        | '\u{200B}'..='\u{200F}'
+        | '\u{202A}'..='\u{202E}'
+        | '\u{2060}'..='\u{2064}'
+        | '\u{FE00}'..='\u{FE0F}'
+        | '\u{FEFF}'
+        | '\u{FFF9}'..='\u{FFFC}'
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::configuration::ConfigurationBuilder;
+
+  fn run_test(text: &str, expected: &str, unescape_printable: bool) {
+    let config = ConfigurationBuilder::new()
+      .string_literal_remove_unnecessary_escapes(true)
+      .string_literal_unicode_escape_case(LetterCase::Uppercase)
+      .string_literal_unescape_printable(unescape_printable)
+      .build();
+    let result = get_string_text_with_normalized_escapes(text, &config);
+    assert_eq!(result, expected);
+    assert_eq!(get_value(&result), get_value(text), "value changed for {}", text);
+  }
+
+  /// Gets the UTF-16 code units of the string value.
+  fn get_value(text: &str) -> Vec<u16> {
+    let mut value = Vec::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+      if c != '\\' {
+        value.extend(c.encode_utf16(&mut [0; 2]).iter());
+        continue;
+      }
+      let escaped_char = chars.next().unwrap();
+      let code_point = match escaped_char {
+        'x' | 'u' => {
+          let rest_text = chars.as_str();
+          let (code_point, _, len) = parse_code_escape(escaped_char, rest_text).unwrap();
+          chars = rest_text[len..].chars();
+          code_point
+        }
+        'b' => 0x08,
+        'f' => 0x0C,
+        'n' => 0x0A,
+        'r' => 0x0D,
+        't' => 0x09,
+        'v' => 0x0B,
+        '0' => 0,
+        _ => escaped_char as u32,
+      };
+      match char::from_u32(code_point) {
+        Some(c) => value.extend(c.encode_utf16(&mut [0; 2]).iter()),
+        None => value.push(code_point as u16),
+      }
+    }
+    value
+  }
+
+  #[test]
+  fn it_should_remove_unnecessary_escapes() {
+    run_test(r"\d\-\a", "d-a", false);
+    run_test(r"\n\t\\\0\'\`", r"\n\t\\\0\'`", false);
+  }
+
+  #[test]
+  fn it_should_normalize_unicode_escape_case() {
+    run_test(r"\u00e9\x0a\u{1f600}", r"\u00E9\x0A\u{1F600}", false);
+  }
+
+  #[test]
+  fn it_should_unescape_printable_chars() {
+    run_test(r"\x41\u00e9\u{1F600}\uD83D\uDE00", "Aé😀😀", true);
+    run_test(r"\x5c\x20\xa0\x0a", r"\x5C \xA0\x0A", true);
+    run_test(r"\uD83D", r"\uD83D", true);
+  }
+}
@@ -8,6 +8,7 @@ mod utils;
 
 pub use format_text::format_parsed_source;
 pub use format_text::format_text;
+pub use format_text::format_text_with_host;
 
 #[cfg(feature = "tracing")]
 pub use format_text::trace_file;
@@ -0,0 +1,168 @@
+use deno_ast::swc::common::comments::CommentKind;
+
+use super::get_leading_comments;
+use super::get_shebang_end;
+use super::LeadingComment;
+
+/// Gets the file text with the header comment inserted after any shebang or with the
+/// existing header comment replaced when it's a variant of the header (ex. a different year).
+///
+/// The header is used as-is when it's a comment, otherwise each line becomes a line comment.
+/// Returns `None` when the file text already starts with the header.
+pub fn get_file_text_with_header(file_text: &str, header: &str) -> Option<String> {
+  let header = get_header_comment_text(header);
+  let comments_start = get_shebang_end(file_text);
+
+  if let Some((start, end)) = get_first_comment_range(&get_leading_comments(file_text), file_text) {
+    let existing_text = &file_text[start..end];
+    if existing_text.lines().map(|line| line.trim_end()).eq(header.lines()) {
+      return None;
+    }
+    if is_header_variant(existing_text, &header) {
+      return Some(format!("{}{}{}", &file_text[..start], header, &file_text[end..]));
+    }
+  }
+
+  let text_before = &file_text[..comments_start];
+  let text_after = file_text[comments_start..].trim_start_matches(['\r', '\n']);
+  Some(if text_after.is_empty() {
+    format!("{}{}\n", text_before, header)
+  } else {
+    format!("{}{}\n\n{}", text_before, header, text_after)
+  })
+}
+
+fn get_header_comment_text(header: &str) -> String {
+  let header = header.trim();
+  if header.starts_with("//") || header.starts_with("/*") {
+    header.lines().map(|line| line.trim_end()).collect::<Vec<_>>().join("\n")
+  } else {
+    header
+      .lines()
+      .map(|line| {
+        if line.trim().is_empty() {
+          "//".to_string()
+        } else {
+          format!("// {}", line.trim_end())
+        }
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+/// Gets the range of the first block comment or group of line comments on consecutive lines.
+fn get_first_comment_range(comments: &[LeadingComment], file_text: &str) -> Option<(usize, usize)> {
+  let first_comment = comments.first()?;
+  let mut end = first_comment.end;
+  if first_comment.kind == CommentKind::Line {
+    for comment in comments.iter().skip(1) {
+      let is_next_line = file_text[end..comment.start].matches('\n').count() == 1;
+      if comment.kind != CommentKind::Line || !is_next_line {
+        break;
+      }
+      end = comment.end;
+    }
+  }
+  Some((first_comment.start, end))
+}
+
+/// Gets if the comment has the same words as the header ignoring numbers (ex. years),
+/// case, punctuation, and how the comment is written.
+fn is_header_variant(comment_text: &str, header: &str) -> bool {
+  let comment_words = get_words(comment_text);
+  !comment_words.is_empty() && comment_words == get_words(header)
+}
+
+fn get_words(text: &str) -> Vec<String> {
+  text
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty() && !word.chars().all(|c| c.is_numeric()))
+    .map(|word| word.to_lowercase())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const HEADER: &str = "Copyright 2022 Example Inc. All rights reserved.";
+
+  fn run_test(text: &str, expected: Option<&str>) {
+    assert_eq!(get_file_text_with_header(text, HEADER).as_deref(), expected);
+  }
+
+  #[test]
+  fn it_should_insert_when_missing() {
+    run_test("test;\n", Some("// Copyright 2022 Example Inc. All rights reserved.\n\ntest;\n"));
+    run_test("", Some("// Copyright 2022 Example Inc. All rights reserved.\n"));
+  }
+
+  #[test]
+  fn it_should_insert_after_shebang_and_before_other_comments() {
+    run_test(
+      "#!/usr/bin/env node\n// other\ntest;\n",
+      Some("#!/usr/bin/env node\n// Copyright 2022 Example Inc. All rights reserved.\n\n// other\ntest;\n"),
+    );
+  }
+
+  #[test]
+  fn it_should_not_change_when_exists() {
+    run_test("// Copyright 2022 Example Inc. All rights reserved.\n\ntest;\n", None);
+  }
+
+  #[test]
+  fn it_should_replace_variants() {
+    run_test(
+      "// copyright 2019 Example Inc.  All rights reserved\ntest;\n",
+      Some("// Copyright 2022 Example Inc. All rights reserved.\ntest;\n"),
+    );
+    run_test(
+      "/*\n * Copyright 2022 Example Inc.\n * All rights reserved.\n */\ntest;\n",
+      Some("// Copyright 2022 Example Inc. All rights reserved.\ntest;\n"),
+    );
+  }
+
+  #[test]
+  fn it_should_replace_short_variants_with_a_different_year() {
+    assert_eq!(
+      get_file_text_with_header("/*! Copyright 2019 Ex */\ntest;\n", "Copyright 2022 Ex").as_deref(),
+      Some("// Copyright 2022 Ex\ntest;\n"),
+    );
+    assert_eq!(
+      get_file_text_with_header("// (c) 2019-2021 Ex\ntest;\n", "(c) 2022 Ex").as_deref(),
+      Some("// (c) 2022 Ex\ntest;\n"),
+    );
+  }
+
+  #[test]
+  fn it_should_not_replace_short_comments_with_other_words() {
+    assert_eq!(
+      get_file_text_with_header("// Copyright 2019 Other\ntest;\n", "Copyright 2022 Ex").as_deref(),
+      Some("// Copyright 2022 Ex\n\n// Copyright 2019 Other\ntest;\n"),
+    );
+  }
+
+  #[test]
+  fn it_should_not_replace_the_license_of_another_project() {
+    run_test(
+      "// Copyright 2020 Other Inc. All rights reserved.\ntest;\n",
+      Some("// Copyright 2022 Example Inc. All rights reserved.\n\n// Copyright 2020 Other Inc. All rights reserved.\ntest;\n"),
+    );
+    run_test(
+      "/*\n * Copyright 2022 Example Inc. All rights reserved.\n * Licensed under the MIT license.\n */\ntest;\n",
+      Some(concat!(
+        "// Copyright 2022 Example Inc. All rights reserved.\n\n",
+        "/*\n * Copyright 2022 Example Inc. All rights reserved.\n * Licensed under the MIT license.\n */\ntest;\n"
+      )),
+    );
+  }
+
+  #[test]
+  fn it_should_only_replace_the_first_group_of_line_comments() {
+    run_test(
+      "// Copyright 2021 Example Inc.\n// All rights reserved.\n\n// other\ntest;\n",
+      Some("// Copyright 2022 Example Inc. All rights reserved.\n\n// other\ntest;\n"),
+    );
+  }
+}
@@ -1,64 +1,16 @@
-use crate::utils::char_iterator::IteratorCharExt;
-use std::{
-  iter::{Iterator, Peekable},
-  str::Chars,
-};
+use deno_ast::swc::common::comments::CommentKind;
 
-pub fn file_text_has_ignore_comment(file_text: &str, ignore_text: &str) -> bool {
-  let mut chars = file_text.chars().peekable();
-
-  // skip over the shebang
-  if file_text.starts_with("#!") {
-    chars.next();
-    chars.next();
-    chars.skip_all_until_new_line();
-  }
-
-  // now handle the comments
-  while chars.peek().is_some() {
-    chars.skip_whitespace();
-    if chars.next() != Some('/') {
-      return false;
-    }
-    match chars.next() {
-      Some('/') => {
-        if check_single_line_comment(&mut chars, ignore_text) {
-          return true;
-        }
-      }
-      Some('*') => {
-        if check_multi_line_comment(&mut chars, ignore_text) {
-          return true;
-        }
-      }
-      _ => return false,
-    }
-  }
-  return false;
+use super::get_leading_comments;
 
-  fn check_single_line_comment(chars: &mut Peekable<Chars>, ignore_text: &str) -> bool {
-    chars.skip_spaces(); // only spaces, not whitespace
-    if chars.check_text(ignore_text) {
-      return true;
-    }
-    chars.skip_all_until_new_line();
-    false
-  }
-
-  fn check_multi_line_comment(chars: &mut Peekable<Chars>, ignore_text: &str) -> bool {
-    chars.skip_whitespace();
-    if chars.check_text(ignore_text) {
-      return true;
-    }
-
-    while let Some(c) = chars.next() {
-      if c == '*' && chars.peek() == Some(&'/') {
-        chars.next();
-        return false;
-      }
-    }
-    false
-  }
+pub fn file_text_has_ignore_comment(file_text: &str, ignore_text: &str) -> bool {
+  get_leading_comments(file_text).iter().any(|comment| {
+    let text = match comment.kind {
+      // only spaces, not whitespace
+      CommentKind::Line => comment.text.trim_start_matches(' '),
+      CommentKind::Block => comment.text.trim_start(),
+    };
+    text.starts_with(ignore_text)
+  })
 }
 
 #[cfg(test)]
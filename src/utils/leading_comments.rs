@@ -0,0 +1,88 @@
+use deno_ast::swc::common::comments::CommentKind;
+
+/// A comment before any code in a file.
+pub struct LeadingComment<'a> {
+  pub kind: CommentKind,
+  /// Start of the comment including the slashes.
+  pub start: usize,
+  /// End of the comment excluding any trailing whitespace of a line comment.
+  pub end: usize,
+  /// Text of the comment without the slashes.
+  pub text: &'a str,
+}
+
+/// Gets the end of the shebang (ex. `#!/usr/bin/env node`) including its new line or 0 when there is none.
+pub fn get_shebang_end(file_text: &str) -> usize {
+  if file_text.starts_with("#!") {
+    file_text.find('\n').map(|index| index + 1).unwrap_or(file_text.len())
+  } else {
+    0
+  }
+}
+
+/// Gets the comments at the start of the file after any shebang.
+pub fn get_leading_comments(file_text: &str) -> Vec<LeadingComment<'_>> {
+  let mut comments = Vec::new();
+  let mut pos = get_shebang_end(file_text);
+
+  loop {
+    let start = pos + (file_text[pos..].len() - file_text[pos..].trim_start().len());
+    let text = &file_text[start..];
+    if let Some(comment_text) = text.strip_prefix("//") {
+      let comment_text = comment_text[..comment_text.find('\n').unwrap_or(comment_text.len())].trim_end();
+      pos = start + 2 + comment_text.len();
+      comments.push(LeadingComment {
+        kind: CommentKind::Line,
+        start,
+        end: pos,
+        text: comment_text,
+      });
+    } else if let Some(comment_text) = text.strip_prefix("/*") {
+      match comment_text.find("*/") {
+        Some(index) => {
+          pos = start + 2 + index + 2;
+          comments.push(LeadingComment {
+            kind: CommentKind::Block,
+            start,
+            end: pos,
+            text: &comment_text[..index],
+          });
+        }
+        None => break,
+      }
+    } else {
+      break;
+    }
+  }
+
+  comments
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn run_test(text: &str, expected: Vec<(&str, &str)>) {
+    let comments = get_leading_comments(text);
+    assert_eq!(comments.iter().map(|c| (&text[c.start..c.end], c.text)).collect::<Vec<_>>(), expected);
+  }
+
+  #[test]
+  fn it_should_get_the_comments_before_code() {
+    run_test(
+      "// a  \r\n\n/* b\n*/ // c\ntest; // d",
+      vec![("// a", " a"), ("/* b\n*/", " b\n"), ("// c", " c")],
+    );
+  }
+
+  #[test]
+  fn it_should_skip_over_shebang() {
+    run_test("#!/usr/bin/env node\n// a\n", vec![("// a", " a")]);
+    run_test("#!/usr/bin/env node", vec![]);
+  }
+
+  #[test]
+  fn it_should_stop_at_an_unterminated_block_comment() {
+    run_test("// a\n/* b", vec![("// a", " a")]);
+  }
+}
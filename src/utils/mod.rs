@@ -1,13 +1,17 @@
-mod char_iterator;
+mod file_header;
 mod file_text_has_ignore_comment;
 mod is_prefix_semi_colon_insertion_char;
+mod leading_comments;
 mod stack;
 mod string_utils;
 mod vec_map;
+mod wrap_text;
 
-pub use char_iterator::*;
+pub use file_header::*;
 pub use file_text_has_ignore_comment::*;
 pub use is_prefix_semi_colon_insertion_char::*;
+pub use leading_comments::*;
 pub use stack::*;
 pub use string_utils::*;
 pub use vec_map::*;
+pub use wrap_text::*;
@@ -97,6 +97,31 @@ pub fn split_lines(text: &str) -> SplitLinesIterator<'_> {
   SplitLinesIterator { inner: text.split('\n') }
 }
 
+/// Gets if the text matches the pattern where a `*` in the pattern matches any text.
+pub fn is_wildcard_match(text: &str, pattern: &str) -> bool {
+  let mut parts = pattern.split('*');
+  let mut remaining = match text.strip_prefix(parts.next().unwrap()) {
+    Some(remaining) => remaining,
+    None => return false,
+  };
+  let parts = parts.collect::<Vec<_>>();
+  if parts.is_empty() {
+    return remaining.is_empty();
+  }
+
+  for (i, part) in parts.iter().enumerate() {
+    if i == parts.len() - 1 {
+      return remaining.ends_with(part);
+    }
+    match remaining.find(part) {
+      Some(index) => remaining = &remaining[index + part.len()..],
+      None => return false,
+    }
+  }
+
+  unreachable!()
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -114,4 +139,22 @@ mod test {
     let lines = split_lines(&text).collect::<Vec<_>>();
     assert_eq!(lines, vec!["a ", "   b", "c"]);
   }
+
+  #[test]
+  fn is_wildcard_match_without_wildcard() {
+    assert!(is_wildcard_match("css", "css"));
+    assert!(!is_wildcard_match("css2", "css"));
+    assert!(!is_wildcard_match("cs", "css"));
+  }
+
+  #[test]
+  fn is_wildcard_match_with_wildcards() {
+    assert!(is_wildcard_match("styled.div", "styled.*"));
+    assert!(is_wildcard_match("styled.div.attrs({})", "styled.*"));
+    assert!(!is_wildcard_match("styled", "styled.*"));
+    assert!(is_wildcard_match("styled(Button)", "styled(*)"));
+    assert!(!is_wildcard_match("styled(Button).attrs", "styled(*)"));
+    assert!(is_wildcard_match("a.b.c", "*.*"));
+    assert!(is_wildcard_match("anything", "*"));
+  }
 }
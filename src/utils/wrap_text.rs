@@ -0,0 +1,95 @@
+/// Wraps the words of the text to the provided widths.
+///
+/// Inline `{@link}`, brace, and backtick spans are never broken and words that would
+/// change the meaning of the text when at the start of a line (ex. `-`) are kept on the
+/// previous line. Returns `None` when the text has an unclosed span.
+pub fn wrap_text(text: &str, first_line_max_width: usize, max_width: usize) -> Option<Vec<String>> {
+  let mut lines = Vec::new();
+  let mut line = String::new();
+
+  for word in get_words(text)? {
+    let max_width = if lines.is_empty() { first_line_max_width } else { max_width };
+    if line.is_empty() {
+      line.push_str(word);
+    } else if line.chars().count() + 1 + word.chars().count() <= max_width || !can_start_wrapped_line(word) {
+      line.push(' ');
+      line.push_str(word);
+    } else {
+      lines.push(std::mem::take(&mut line));
+      line.push_str(word);
+    }
+  }
+  if !line.is_empty() {
+    lines.push(line);
+  }
+
+  Some(lines)
+}
+
+/// Gets if a word can be at the start of a line without changing its meaning (ex. a Markdown list marker can't).
+pub fn can_start_wrapped_line(word: &str) -> bool {
+  let is_list_marker = matches!(word, "-" | "*" | "+")
+    || (word.ends_with('.') || word.ends_with(')')) && word.len() > 1 && word[..word.len() - 1].chars().all(|c| c.is_ascii_digit());
+  !is_list_marker && !word.starts_with(['@', '#', '>', '|']) && !word.starts_with("```")
+}
+
+fn get_words(text: &str) -> Option<Vec<&str>> {
+  let mut words = Vec::new();
+  let mut word_start = None;
+  let mut is_in_backticks = false;
+  let mut brace_depth = 0;
+
+  for (byte_index, c) in text.char_indices() {
+    if c.is_whitespace() && !is_in_backticks && brace_depth == 0 {
+      if let Some(start) = word_start.take() {
+        words.push(&text[start..byte_index]);
+      }
+      continue;
+    }
+
+    if word_start.is_none() {
+      word_start = Some(byte_index);
+    }
+    match c {
+      '`' => is_in_backticks = !is_in_backticks,
+      '{' if !is_in_backticks => brace_depth += 1,
+      '}' if !is_in_backticks && brace_depth > 0 => brace_depth -= 1,
+      _ => {}
+    }
+  }
+
+  if is_in_backticks || brace_depth > 0 {
+    return None;
+  }
+  if let Some(start) = word_start {
+    words.push(&text[start..]);
+  }
+  Some(words)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn wrap_text_wraps_words() {
+    assert_eq!(
+      wrap_text("a bb ccc dd e", 4, 6),
+      Some(vec!["a bb".to_string(), "ccc dd".to_string(), "e".to_string()])
+    );
+  }
+
+  #[test]
+  fn wrap_text_keeps_spans_together() {
+    assert_eq!(
+      wrap_text("a {@link b c} `d e`", 3, 3),
+      Some(vec!["a".to_string(), "{@link b c}".to_string(), "`d e`".to_string()])
+    );
+    assert_eq!(wrap_text("a `b c", 3, 3), None);
+  }
+
+  #[test]
+  fn wrap_text_does_not_start_line_with_list_marker() {
+    assert_eq!(wrap_text("a b - c", 3, 3), Some(vec!["a b -".to_string(), "c".to_string()]));
+  }
+}
@@ -50,9 +50,9 @@ impl PluginHandler<Configuration> for TypeScriptPluginHandler {
     file_path: &Path,
     file_text: &str,
     config: &Configuration,
-    _format_with_host: impl FnMut(&Path, String, &ConfigKeyMap) -> Result<String>,
+    format_with_host: impl FnMut(&Path, String, &ConfigKeyMap) -> Result<String>,
   ) -> Result<String> {
-    super::format_text(file_path, file_text, config)
+    super::format_text_with_host(file_path, file_text, config, format_with_host)
   }
 }
 
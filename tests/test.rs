@@ -5,6 +5,7 @@ extern crate dprint_plugin_typescript;
 
 use std::collections::HashMap;
 use std::fs::{self};
+use std::path::Path;
 use std::path::PathBuf;
 use std::time::Instant;
 
@@ -37,6 +38,48 @@ fn test_performance() {
   }
 }
 
+/// A fake host formatter that indents CSS based on its braces and places each
+/// selector on its own line when a selector list exceeds the line width.
+fn format_with_host(file_path: &Path, file_text: String, config: &ConfigKeyMap) -> anyhow::Result<String> {
+  if file_path.extension().and_then(|ext| ext.to_str()) != Some("css") {
+    return Ok(file_text);
+  }
+  if file_text.contains("syntax-error") {
+    anyhow::bail!("Syntax error.");
+  }
+
+  let get_number = |key: &str| match config.get(key) {
+    Some(ConfigKeyValue::Number(value)) => *value as usize,
+    _ => panic!("Expected a number for {}.", key),
+  };
+  let line_width = get_number("lineWidth");
+  let indent_text = match config.get("useTabs") {
+    Some(ConfigKeyValue::Bool(true)) => String::from("\t"),
+    Some(ConfigKeyValue::Bool(false)) => " ".repeat(get_number("indentWidth")),
+    _ => panic!("Expected a boolean for useTabs."),
+  };
+  let mut result = String::new();
+  let mut depth = 0;
+  for line in file_text.lines().map(|line| line.trim()).filter(|line| !line.is_empty()) {
+    if line.starts_with('}') {
+      depth -= 1;
+    }
+    let indent = indent_text.repeat(depth);
+    if line.ends_with('{') && indent.len() + line.len() > line_width {
+      result.push_str(&indent);
+      result.push_str(&line.split(", ").collect::<Vec<_>>().join(&format!(",\n{}", indent)));
+    } else {
+      result.push_str(&indent);
+      result.push_str(line);
+    }
+    result.push('\n');
+    if line.ends_with('{') {
+      depth += 1;
+    }
+  }
+  Ok(result)
+}
+
 #[test]
 fn test_specs() {
   //debug_here!();
@@ -55,7 +98,12 @@ fn test_specs() {
         let config_result = resolve_config(parse_config_key_map(spec_config), &global_config);
         ensure_no_diagnostics(&config_result.diagnostics);
 
-        format_text(&file_name, &file_text, &config_result.config)
+        // only use the host when necessary in order to keep testing the code path without one
+        if config_result.config.tagged_template_file_extensions.is_empty() {
+          format_text(&file_name, &file_text, &config_result.config)
+        } else {
+          format_text_with_host(&file_name, &file_text, &config_result.config, format_with_host)
+        }
       }
     },
     move |_file_name, _file_text, _spec_config| {